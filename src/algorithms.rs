@@ -1,24 +1,122 @@
 pub use binary_search::binary_search;
 pub use binary_search::binary_search_for_tree;
+pub use binary_search::binary_search_instrumented;
+pub use binary_search::gallop;
+pub use binary_search::partition_point;
+pub use binary_search::Search;
+pub use breadth_first_search::bfs_distances;
 pub use breadth_first_search::breadth_first_search;
+pub use breadth_first_search::breadth_first_search_limited;
+pub use breadth_first_search::breadth_first_search_visit;
+pub use breadth_first_search::find_by_id;
+pub use breadth_first_search::reachable_sinks;
+pub use breadth_first_search::shortest_path;
+pub use connected_components::connected_components;
+pub use connected_components::is_connected;
 pub use depth_first_search::depth_first_search;
+pub use depth_first_search::iterative_deepening_dfs;
+pub use depth_first_search::root_to_leaf_paths;
+pub use dijkstra_search::dijkstra_all_distances;
+pub use dijkstra_search::dijkstra_multi_source;
 pub use dijkstra_search::dijkstra_search;
+pub use dijkstra_search::dijkstra_search_sync;
+pub use dijkstra_search::dijkstra_tree;
+pub use dijkstra_search::has_path;
+pub use external_merge_sort::external_merge_sort;
+pub use extremum::extremum;
+pub use find_cycle::find_cycle;
+pub use find_cycle::has_cycle;
+pub use heapify::heapify;
+pub use heapify::is_heap;
+pub use heapify::sift_down;
+pub use heapify::sift_up;
+pub use is_stable_sorted::is_stable_sorted;
+pub use k_shortest_paths::k_shortest_paths;
+pub use longest_increasing_subsequence::longest_increasing_subsequence;
+pub use majority_element::majority_element;
+pub use max_flow::max_flow;
+pub use max_subarray::max_subarray;
+pub use merge_sort::merge_in_place;
+pub use merge_sort::merge_sort;
+pub use merge_sort::merge_sort_by;
+pub use merge_sort::merge_sort_with_cutoff;
+pub use merge_sort::MERGE_SORT_CUTOFF;
+pub use numeric::gcd;
+pub use numeric::lcm;
+pub use numeric::pow;
+pub use numeric::pow_mod;
+pub use quick_sort::partition;
 pub use quick_sort::quick_sort;
+pub use quick_sort::quick_sort_by;
+pub use quick_sort::quick_sort_range;
+pub use rabin_karp::rabin_karp;
+pub use run_length_encoding::rle_decode;
+pub use run_length_encoding::rle_encode;
 pub use selection_sort::selection_sort;
 pub use selection_sort::selection_sort_by_key;
+pub use sieve_of_eratosthenes::primes_up_to;
+pub use sort_comparisons::counting_cmp;
+pub use sort_comparisons::merge_sort_counted;
+pub use sort_comparisons::quick_sort_counted;
+pub use strongly_connected_components::strongly_connected_components;
+pub use ternary_search::ternary_search;
+pub use tim_sort::find_run;
+pub use tim_sort::tim_sort;
+pub use top_k::top_k;
+pub use transpose::transpose;
+pub use tree_diameter::diameter;
+pub use two_sum::two_sum;
+pub use zero_one_bfs::zero_one_bfs;
 
 mod binary_search;
 mod breadth_first_search;
+mod connected_components;
 mod depth_first_search;
 mod dijkstra_search;
+mod external_merge_sort;
+mod extremum;
+mod find_cycle;
+mod heapify;
 mod insertion_sort;
+mod is_stable_sorted;
 mod k_nearest_neighbor;
+mod k_shortest_paths;
+mod longest_increasing_subsequence;
+mod majority_element;
+mod max_flow;
+mod max_subarray;
 mod merge_sort;
+mod numeric;
 mod quick_sort;
+mod rabin_karp;
+mod run_length_encoding;
 mod selection_sort;
+mod sieve_of_eratosthenes;
+mod sort_comparisons;
+mod strongly_connected_components;
+mod ternary_search;
+mod tim_sort;
+mod top_k;
+mod transpose;
+mod tree_diameter;
+mod two_sum;
+mod zero_one_bfs;
 
 #[derive(Clone, Copy)]
 pub enum Order {
     Desc,
     Asc,
 }
+
+/// A pseudo-random list of `len` numbers in `0..modulo` for tests of sorts. It's a simple LCG, so the same `seed` always gives the same list.
+#[cfg(test)]
+pub(crate) fn get_random_list(len: usize, seed: u32, modulo: i32) -> Vec<i32> {
+    let mut seed = seed;
+
+    (0..len)
+        .map(|_| {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            (seed >> 16) as i32 % modulo
+        })
+        .collect()
+}
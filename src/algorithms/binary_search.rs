@@ -15,30 +15,42 @@ use std::rc::Rc;
 /// # Explanation
 /// This algorithm works **ONLY** with sorted lists.
 ///
-/// It takes 0 index as a `low` position and latest index as a `high` position, then it follows next steps in a loop:
-/// - if `low` is `high`, then it means we reached the end of our vector, so there is no desired element in our list, return `None`
-/// - Calculating a middle element index by `(low + high) / 2` and compares it to the desired element
+/// It takes 0 index as a `low` position and the length of the list as a `high` position(`high` itself is never checked, it's an exclusive bound), then it follows next steps in a loop:
+/// - if `low` is `high`, then it means there is nothing left to check, so there is no desired element in our list, return `None`
+/// - Calculating a middle element index by `low + (high - low) / 2` and compares it to the desired element
 /// - if middle element is desired element, then return `Some(mid)`
-/// - else if middle element is bigger than the desired one, then we shift `high` to `mid - 1`(we don't need to keep `mid` index as we already know that it is wrong). Or in other words we take a vector slice on the left from the middle element as the desired element is lower that current middle one.
-/// - else if middle element is lower than the desired one, then we shift `low` to `mid + 1`(we don't need to keep `mid` index as we already know that it is wrong). Or in other words we take a vector slice on the right from the middle element as the desired element is bigger that current middle one.
+/// - else if middle element is bigger than the desired one, then we shift `high` to `mid`(`high` is exclusive, so `mid` won't be checked again). Or in other words we take a slice on the left from the middle element as the desired element is lower that current middle one.
+/// - else if middle element is lower than the desired one, then we shift `low` to `mid + 1`(we don't need to keep `mid` index as we already know that it is wrong). Or in other words we take a slice on the right from the middle element as the desired element is bigger that current middle one.
 pub fn binary_search<T>(list: &[T], element: &T) -> Option<usize>
 where
-    T: Eq + Ord,
+    T: Ord,
+{
+    binary_search_instrumented(list, element).0
+}
+
+/// The same as `binary_search`, but it also returns a number of probes(comparisons with middle elements) it took to get the result.
+/// It's useful to see `O(log n)` in action: for a list of `n` elements there are never more than `log2(n) + 1` probes.
+pub fn binary_search_instrumented<T>(list: &[T], element: &T) -> (Option<usize>, usize)
+where
+    T: Ord,
 {
     let mut low = 0;
-    let mut high = list.len() - 1;
+    let mut high = list.len();
+    let mut probes = 0;
 
     loop {
-        let mid = (low + high) / 2;
-
         if low == high {
-            break None;
+            break (None, probes);
         }
 
+        let mid = low + (high - low) / 2;
+
+        probes += 1;
+
         match element.cmp(&list[mid]) {
-            Ordering::Equal => break Some(mid),
+            Ordering::Equal => break (Some(mid), probes),
             Ordering::Less => {
-                high = mid - 1;
+                high = mid;
             }
             Ordering::Greater => {
                 low = mid + 1;
@@ -46,14 +58,84 @@ where
         }
     }
 }
+/// # Description
+/// Binary search "on the answer". Instead of searching for an element in a list, we search for the first index in `0..len` for which `predicate` becomes `true`.
+///
+/// It works **ONLY** with monotonic predicates, i.e. `predicate` must return `false` for all indexes up to some point and `true` for all indexes after it.
+/// This makes it useful beyond sorted lists, for example to find a minimal value which satisfies some condition(minimal capacity, minimal speed etc.).
+///
+/// Returns `len` if `predicate` is `false` for all indexes.
+///
+/// # Complexity
+/// O(log n), where `n` is `len`, `predicate` is called that many times.
+pub fn partition_point<F>(len: usize, predicate: F) -> usize
+where
+    F: Fn(usize) -> bool,
+{
+    let mut low = 0;
+    let mut high = len;
+
+    // `high` is always an index where `predicate` is `true`(or `len`), everything below `low` is `false`
+    while low < high {
+        let mid = low + (high - low) / 2;
+
+        if predicate(mid) {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+
+    low
+}
+
+/// # Description
+/// Galloping(exponential) search finds an insertion point of `element` in a sorted `list`: the first index at or after `from` where an item is not lower than `element`.
+/// `from` is a hint, everything before it is expected to be lower than `element`.
+///
+/// When we merge a short list into a long one, the next insertion point is usually close to the previous one,
+/// so instead of binary search over the whole rest of the list we check `from`, `from + 1`, `from + 3`, `from + 7`... doubling the step,
+/// and then do binary search only between the last two probes.
+///
+/// Returns `list.len()` if all items from `from` are lower than `element`.
+///
+/// # Complexity
+/// `O(log d)`, where `d` is a distance from `from` to the insertion point, so it's never worse than binary search and much faster for close points.
+pub fn gallop<T>(list: &[T], element: &T, from: usize) -> usize
+where
+    T: Ord,
+{
+    let from = from.min(list.len());
+    let rest = &list[from..];
+    let mut step = 1;
+    // `low` is an offset of an item which is known to be lower than `element`(or `0` at the beginning), `high` is the next probe
+    let mut low = 0;
+    let mut high = 0;
+
+    while high < rest.len() && &rest[high] < element {
+        low = high + 1;
+        high += step;
+        step *= 2;
+    }
+
+    let high = high.min(rest.len());
+
+    from + low + partition_point(high - low, |index| &rest[low + index] >= element)
+}
+
+/// Searches a node with `desired_value` going from the head down. An empty tree(new, cleared or with all nodes removed) has no head, so nothing is found in it.
 pub fn binary_search_for_tree<V, K>(
     tree: &AVLTree<V, K>,
     desired_value: &V,
 ) -> Option<Rc<BinarySearchTreeNode<V, K>>>
 where
     V: Eq + Ord,
-    K: Hash + Eq + Copy + Debug,
+    K: Hash + Eq + Clone + Debug,
 {
+    if tree.is_empty() {
+        return None;
+    }
+
     let mut current_node = Rc::clone(tree.head());
 
     loop {
@@ -76,9 +158,46 @@ where
     }
 }
 
+/// # Description
+/// A common interface for searching in sorted collections, so generic code can search in a sorted slice or in a tree the same way.
+/// `Found` is what a collection can tell about a found item: an index for a slice, a node for a tree.
+pub trait Search<T> {
+    type Found;
+
+    fn find(&self, target: &T) -> Option<Self::Found>;
+}
+
+/// Searches with `binary_search`, so the slice must be sorted.
+impl<T> Search<T> for [T]
+where
+    T: Ord,
+{
+    type Found = usize;
+
+    fn find(&self, target: &T) -> Option<usize> {
+        binary_search(self, target)
+    }
+}
+
+/// Searches with `binary_search_for_tree`.
+impl<V, K> Search<V> for AVLTree<V, K>
+where
+    V: Eq + Ord,
+    K: Hash + Eq + Clone + Debug,
+{
+    type Found = Rc<BinarySearchTreeNode<V, K>>;
+
+    fn find(&self, target: &V) -> Option<Self::Found> {
+        binary_search_for_tree(self, target)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{binary_search, binary_search_for_tree};
+    use super::{
+        binary_search, binary_search_for_tree, binary_search_instrumented, gallop, partition_point,
+        Search,
+    };
     use crate::binary_search_tree::AVLTree;
 
     fn get_list() -> Vec<i32> {
@@ -100,6 +219,30 @@ mod tests {
         tree
     }
 
+    fn contains_all<S>(collection: &S, targets: &[i32]) -> bool
+    where
+        S: Search<i32> + ?Sized,
+    {
+        targets
+            .iter()
+            .all(|target| collection.find(target).is_some())
+    }
+
+    #[test]
+    fn should_search_vector_and_tree_the_same_way() {
+        // given
+        let list = vec![3, 4, 5, 6, 8, 11, 20];
+        let tree = get_binary_tree();
+
+        // then
+        assert!(contains_all(list.as_slice(), &[3, 8, 20]));
+        assert!(contains_all(&tree, &[3, 8, 20]));
+        assert!(!contains_all(list.as_slice(), &[3, 7]));
+        assert!(!contains_all(&tree, &[3, 7]));
+        assert_eq!(Some(4), list.find(&8));
+        assert_eq!(&8, tree.find(&8).unwrap().value());
+    }
+
     #[test]
     fn should_find_item_in_vector() {
         assert_eq!(binary_search::<i32>(&get_list(), &28), Some(28));
@@ -107,6 +250,77 @@ mod tests {
     #[test]
     fn should_return_none_if_not_exist_in_vector() {
         assert_eq!(binary_search::<i32>(&get_list(), &45), None);
+        assert_eq!(binary_search::<i32>(&get_list(), &-1), None);
+    }
+    #[test]
+    fn should_find_first_and_last_items_in_vector() {
+        assert_eq!(binary_search::<i32>(&get_list(), &0), Some(0));
+        assert_eq!(binary_search::<i32>(&get_list(), &32), Some(32));
+    }
+    #[test]
+    fn should_return_none_for_empty_vector() {
+        assert_eq!(binary_search::<i32>(&[], &1), None);
+    }
+
+    #[test]
+    fn should_count_probes() {
+        let list = get_list();
+        // ceil(log2(33)) + 1
+        let max_probes = 7;
+
+        for element in -1..=33 {
+            let (result, probes) = binary_search_instrumented(&list, &element);
+
+            assert_eq!(binary_search(&list, &element), result);
+            assert!(probes <= max_probes);
+        }
+
+        assert_eq!((Some(16), 1), binary_search_instrumented(&list, &16));
+        assert_eq!((None, 0), binary_search_instrumented(&[], &16));
+    }
+
+    #[test]
+    fn should_find_partition_point() {
+        assert_eq!(partition_point(100, |x| x >= 42), 42);
+        // The smallest number which square is bigger than 1000
+        assert_eq!(partition_point(1000, |x| x * x > 1000), 32);
+    }
+    #[test]
+    fn should_return_len_if_predicate_is_always_false() {
+        assert_eq!(partition_point(100, |_| false), 100);
+        assert_eq!(partition_point(0, |_| true), 0);
+    }
+    #[test]
+    fn should_return_zero_if_predicate_is_always_true() {
+        assert_eq!(partition_point(100, |_| true), 0);
+    }
+
+    #[test]
+    fn should_gallop_from_hint() {
+        // given
+        let list: Vec<i32> = (0..10_000).map(|x| x * 2).collect();
+
+        // then
+        assert_eq!(5, gallop(&list, &10, 3));
+        assert_eq!(6, gallop(&list, &11, 3));
+        assert_eq!(3, gallop(&list, &6, 3));
+        assert_eq!(2_000, gallop(&list, &4_000, 0));
+        assert_eq!(10_000, gallop(&list, &30_000, 10));
+        assert_eq!(10_000, gallop(&list, &0, 20_000));
+        assert_eq!(0, gallop(&[] as &[i32], &1, 0));
+    }
+
+    #[test]
+    fn should_match_partition_point_for_any_hint_before_answer() {
+        let list = get_list();
+
+        for element in -1..35 {
+            let expected = list.partition_point(|item| item < &element);
+
+            for from in 0..=expected {
+                assert_eq!(expected, gallop(&list, &element, from));
+            }
+        }
     }
 
     #[test]
@@ -131,4 +345,17 @@ mod tests {
         // then
         assert!(found_node.is_none());
     }
+
+    #[test]
+    fn should_return_none_for_empty_binary_tree() {
+        let mut tree = AVLTree::new();
+
+        assert!(binary_search_for_tree(&tree, &1).is_none());
+
+        tree.insert(1, 1);
+        tree.remove(&1);
+
+        assert!(binary_search_for_tree(&tree, &1).is_none());
+        assert!(tree.find(&1).is_none());
+    }
 }
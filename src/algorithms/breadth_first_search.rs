@@ -1,7 +1,7 @@
-use crate::graph::{Graph, GraphNode};
-use crate::Queue;
-use std::collections::HashSet;
-use std::fmt::Debug;
+use crate::algorithms::dijkstra_search::build_chain;
+use crate::graph::{BasicGraph, Graph, GraphNode};
+use crate::Traversable;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::hash::Hash;
 use std::rc::Rc;
 
@@ -13,101 +13,466 @@ use std::rc::Rc;
 /// 1. We're going to find a path from the head to a needed node if it exists.
 /// 2. If the path exists, then we're going to find the shortest one.
 ///
-/// `Queue` is used here to make sure that we'll search a layer by a layer, instead of all nodes without any order.
-/// Note that `Queue` is using `LinkedList`, so it won't contribute to capacity.
+/// It works over any `Traversable` nodes, so the same search runs over graphs(e.g. `graph.get(&id)` as the start node) and trees(e.g. `tree.head()`).
+/// The start node itself is not checked, only nodes reachable from it.
+///
+/// `VecDeque` is used here as a queue to make sure that we'll search a layer by a layer, instead of all nodes without any order.
+/// Children are cloned `Rc`s which the `VecDeque` owns, whereas `Queue` keeps only references, so it doesn't fit here.
+///
+/// Nodes don't have to have ids, so already checked nodes are remembered by their addresses(`Rc::as_ptr`), it's the same node if it's the same `Rc` allocation.
 ///
 /// # Complexity
-/// This algorithm has `O(n * e)` complexity, where `n` is a number of nodes and `e` is a number of edges(connections between nodes).
-pub fn breadth_first_search<K, G, N, T, P>(
+/// This algorithm has `O(n + e)` complexity, where `n` is a number of nodes and `e` is a number of edges(connections between nodes).
+pub fn breadth_first_search<N, P>(start_node: &Rc<N>, predicate: P) -> Option<Rc<N>>
+where
+    N: Traversable,
+    P: Fn(&N) -> bool,
+{
+    // Different nodes may point to a same node, so to avoid extra check of already checked nodes - we log them and skip them
+    // It also prevents infinity loop in case if we have 2 nodes which points to each other
+    let mut checked_nodes = HashSet::from([Rc::as_ptr(start_node)]);
+    let mut queue = VecDeque::from(start_node.children());
+
+    while let Some(queue_item) = queue.pop_front() {
+        if !checked_nodes.insert(Rc::as_ptr(&queue_item)) {
+            continue;
+        }
+
+        if predicate(&queue_item) {
+            return Some(queue_item);
+        }
+
+        queue.extend(queue_item.children());
+    }
+
+    None
+}
+
+/// # Description
+/// The same as `breadth_first_search`, but it doesn't go further than `max_depth` edges from the start node, i.e. it answers
+/// "is there a match within N hops". It runs the same depth tracking traversal as `breadth_first_search_visit`: children of the start node are on depth `1`,
+/// and children of nodes on `max_depth` are not added to the queue at all. As in `breadth_first_search`, the start node itself is not checked.
+///
+/// Returns `None` if the start node does not exist or there is no match within `max_depth`.
+///
+/// # Complexity
+/// `O(n + e)`, where `n` and `e` are numbers of nodes and edges within `max_depth`, so on large graphs it can be much less than the whole graph.
+pub fn breadth_first_search_limited<K, G, N, T, P>(
     start_node_id: K,
     graph: &G,
     predicate: P,
+    max_depth: usize,
 ) -> Option<&Rc<N>>
 where
-    T: Debug,
     G: Graph<N, K>,
-    // Debug trait only for Drop trait visualisation, it should be removed if visualisation is not needed
-    N: GraphNode<Value = T> + Debug,
-    K: Eq + Hash + Copy + Debug,
+    N: GraphNode<Id = K, Value = T>,
+    K: Eq + Hash + Clone,
     P: Fn(&T) -> bool,
 {
-    let mut checked_nodes = HashSet::with_capacity(graph.len());
+    traverse_layers(start_node_id, graph, max_depth, |node, depth| {
+        depth > 0 && predicate(node.value())
+    })
+}
+
+/// # Description
+/// Runs the same "layer by layer" traversal as `breadth_first_search`, but instead of stopping at a node matching a predicate,
+/// it goes through all reachable nodes and records a layer of every node, i.e. a number of edges in the shortest path from the start node.
+///
+/// It's handy when we need to answer many "how far is X from the start" questions, as all of them are answered by a single traversal.
+///
+/// The start node has distance `0`, unreachable nodes are not in the result. If the start node does not exist, then the result is empty.
+///
+/// # Complexity
+/// `O(n + e)`, where `n` is a number of nodes and `e` is a number of edges.
+pub fn bfs_distances<K, G, N>(start_node_id: K, graph: &G) -> HashMap<K, usize>
+where
+    G: Graph<N, K>,
+    N: GraphNode<Id = K>,
+    K: Eq + Hash + Clone,
+{
+    let mut distances = HashMap::with_capacity(graph.len());
+
+    breadth_first_search_visit(start_node_id, graph, |id, distance| {
+        distances.insert(id.clone(), distance);
+    });
+
+    distances
+}
+
+/// # Description
+/// Goes through all reachable nodes "layer by layer", the same way as `breadth_first_search`, and calls `on_visit` with an id and a depth of every node
+/// (the start node has depth `0`, its children have depth `1` and so on). Every node is visited once, in BFS order.
+///
+/// Unlike `breadth_first_search` it doesn't stop at a specific node, so it can build any aggregate in one pass, e.g. counts per level or node coloring.
+/// If the start node does not exist, then `on_visit` is never called.
+///
+/// # Complexity
+/// `O(n + e)`, where `n` is a number of nodes and `e` is a number of edges.
+pub fn breadth_first_search_visit<K, G, N, F>(start_node_id: K, graph: &G, mut on_visit: F)
+where
+    G: Graph<N, K>,
+    N: GraphNode<Id = K>,
+    K: Eq + Hash + Clone,
+    F: FnMut(&K, usize),
+{
+    traverse_layers(start_node_id, graph, usize::MAX, |node, depth| {
+        on_visit(node.id(), depth);
+        false
+    });
+}
+
+/// The traversal which all id based BFS functions share. It goes "layer by layer" from `start_node_id` and calls `on_visit` with every reachable node
+/// and its depth, children of nodes on `max_depth` are not added to the queue. When `on_visit` returns `true`, the traversal stops and returns that node.
+///
+/// Graph nodes are taken by reference from the graph, so a `VecDeque` of references is enough. `Queue` isn't used, as it prints every dropped item.
+fn traverse_layers<'g, K, G, N, F>(
+    start_node_id: K,
+    graph: &'g G,
+    max_depth: usize,
+    mut on_visit: F,
+) -> Option<&'g Rc<N>>
+where
+    G: Graph<N, K>,
+    N: GraphNode<Id = K>,
+    K: Eq + Hash + Clone,
+    F: FnMut(&'g Rc<N>, usize) -> bool,
+{
     let head_node = graph.get(&start_node_id)?;
-    let mut queue = Queue::from(head_node.nodes().as_ref()?);
+    let mut depths = HashMap::from([(start_node_id, 0)]);
+    let mut queue = VecDeque::from([head_node]);
+
+    while let Some(queue_item) = queue.pop_front() {
+        let depth = depths[queue_item.id()];
 
-    while let Some(queue_item) = queue.take() {
-        // Different nodes may point to a same node, so to avoid extra check of already checked nodes - we log them and skip them
-        // It also prevents infinity loop in case if we have 2 nodes which points to each other
-        if checked_nodes.contains(queue_item.id()) {
+        if on_visit(queue_item, depth) {
+            return Some(queue_item);
+        }
+
+        if depth == max_depth {
             continue;
         }
 
-        if predicate(queue_item.value()) {
-            return Some(queue_item);
+        for child in queue_item.nodes().borrow().iter() {
+            // The first time we see a node is always the shortest way to it, as we go layer by layer
+            if depths.contains_key(child.id()) {
+                continue;
+            }
+
+            if let Some(child) = graph.get(child.id()) {
+                depths.insert(child.id().clone(), depth + 1);
+                queue.push_back(child);
+            }
+        }
+    }
+
+    None
+}
+
+/// # Description
+/// Returns ids of all sinks(nodes without outgoing edges) which are reachable from `start_node_id`, i.e. it answers
+/// "which terminal states can be reached from here". Nodes are visited by `breadth_first_search_visit`, so sinks are in BFS order.
+///
+/// The start node is reachable from itself, so it's returned if it has no edges. If the start node does not exist, then the result is empty.
+///
+/// # Complexity
+/// `O(n + e)`, where `n` is a number of nodes and `e` is a number of edges.
+pub fn reachable_sinks<T, K>(graph: &BasicGraph<T, K>, start_node_id: K) -> Vec<K>
+where
+    K: Eq + Hash + Clone,
+{
+    let mut sinks = vec![];
+
+    breadth_first_search_visit(start_node_id, graph, |id, _| {
+        if graph.out_degree(id) == Some(0) {
+            sinks.push(id.clone());
+        }
+    });
+
+    sinks
+}
+
+/// # Description
+/// Finds the shortest path(the smallest number of edges) from `start_node_id` to `finish_node_id` in an unweighted graph.
+/// Breadth first traversal already guarantees that the first time we reach a node is via the shortest path,
+/// so we only need to remember a parent of every node when we see it for the first time, and then build a chain from the finish back to the start.
+///
+/// Returns `None` if either node does not exist or `finish_node_id` can't be reached from `start_node_id`.
+///
+/// # Complexity
+/// `O(n + e)`, where `n` is a number of nodes and `e` is a number of edges.
+pub fn shortest_path<K, G, N>(graph: &G, start_node_id: K, finish_node_id: K) -> Option<Vec<K>>
+where
+    G: Graph<N, K>,
+    N: GraphNode<Id = K>,
+    K: Eq + Hash + Clone,
+{
+    let head_node = graph.get(&start_node_id)?;
+    let mut parents = HashMap::new();
+    let mut checked_nodes = HashSet::from([start_node_id]);
+    let mut queue = VecDeque::from([head_node]);
+
+    while let Some(queue_item) = queue.pop_front() {
+        if queue_item.id() == &finish_node_id {
+            return Some(build_chain(finish_node_id, &parents));
         }
 
-        checked_nodes.insert(queue_item.id());
+        for child in queue_item.nodes().borrow().iter() {
+            if checked_nodes.contains(child.id()) {
+                continue;
+            }
 
-        if let Some(nodes) = &queue_item.nodes() {
-            queue.append(nodes);
+            if let Some(child) = graph.get(child.id()) {
+                checked_nodes.insert(child.id().clone());
+                parents.insert(child.id().clone(), queue_item.id().clone());
+                queue.push_back(child);
+            }
         }
     }
 
     None
 }
 
+/// # Description
+/// Finds a node with `node_id` only if it's reachable from `start_node_id`, i.e. it answers "is node X reachable from node Y".
+/// Unlike `graph.get`, which returns any node in the graph regardless of edges, it goes through edges "layer by layer" with the same traversal as `breadth_first_search_visit`.
+///
+/// The start node is always reachable from itself. Returns `None` if either node does not exist or there is no path between them.
+///
+/// # Complexity
+/// `O(n + e)`, where `n` is a number of nodes and `e` is a number of edges.
+pub fn find_by_id<'g, K, G, N>(start_node_id: K, graph: &'g G, node_id: &K) -> Option<&'g Rc<N>>
+where
+    G: Graph<N, K>,
+    N: GraphNode<Id = K>,
+    K: Eq + Hash + Clone,
+{
+    traverse_layers(start_node_id, graph, usize::MAX, |node, _| {
+        node.id() == node_id
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use std::rc::Rc;
 
-    use crate::algorithms::breadth_first_search::breadth_first_search;
+    use crate::algorithms::breadth_first_search::{
+        bfs_distances, breadth_first_search, breadth_first_search_limited,
+        breadth_first_search_visit, find_by_id, reachable_sinks, shortest_path,
+    };
     use crate::graph::{BasicGraph, BasicGraphNode, Graph, GraphNode};
+    use crate::tree::{BasicTree, Tree, TreeNode};
+    use std::collections::HashMap;
 
     #[derive(Debug)]
     struct Item(bool);
 
+    fn get_graph() -> BasicGraph<Item> {
+        let nodes = (1..=8).map(|id| (id, Item(id == 7))).collect();
+
+        BasicGraph::from_adjacency(
+            nodes,
+            &[
+                (1, 2),
+                (1, 3),
+                (2, 4),
+                (2, 5),
+                (3, 6),
+                (3, 7),
+                (3, 5),
+                (6, 8),
+            ],
+        )
+    }
+
     #[test]
     fn should_find_shortest() {
-        let mut graph = BasicGraph::new();
+        let graph = get_graph();
 
-        // Note: I'm not sure how this is viable to make Graph inserting nodes the same way as Tree as in case of Tree we can insert only below and a parent is single
-        // but with Graph we can insert wherever we want with any relations and with as many parents as we want.\
-        // Probably need to get back to it later with fresh ideas.
-        let eight = Rc::new(BasicGraphNode::new(8, Item(false), None));
-        let seven = Rc::new(BasicGraphNode::new(7, Item(true), None));
-        let six = Rc::new(BasicGraphNode::new(
-            6,
-            Item(false),
-            Some(vec![Rc::clone(&eight)]),
-        ));
-        let five = Rc::new(BasicGraphNode::new(5, Item(false), None));
-        let four = Rc::new(BasicGraphNode::new(4, Item(false), None));
-        let three = Rc::new(BasicGraphNode::new(
-            3,
-            Item(false),
-            Some(vec![Rc::clone(&six), Rc::clone(&seven), Rc::clone(&five)]),
-        ));
-        let two = Rc::new(BasicGraphNode::new(
+        assert_eq!(
+            &7,
+            breadth_first_search(graph.get(&1).unwrap(), |x| x.value().0)
+                .unwrap()
+                .id()
+        );
+    }
+
+    #[test]
+    fn should_search_graph_and_tree_through_the_same_interface() {
+        // given
+        let graph = get_graph();
+        let mut tree = BasicTree::from_head(1, false);
+
+        // The same shape as the graph, but 5 has only one parent
+        for (id, parent_id) in [(2, 1), (3, 1), (4, 2), (5, 2), (6, 3), (7, 3), (8, 6)] {
+            tree.insert(id, parent_id, id == 7);
+        }
+
+        // then
+        assert_eq!(
+            &7,
+            breadth_first_search(graph.get(&1).unwrap(), |x| x.value().0)
+                .unwrap()
+                .id()
+        );
+        assert_eq!(
+            &7,
+            breadth_first_search(tree.head(), |x| *x.value())
+                .unwrap()
+                .id()
+        );
+        // The first node of the second layer
+        assert_eq!(
+            &4,
+            breadth_first_search(tree.head(), |x| *x.id() > 3)
+                .unwrap()
+                .id()
+        );
+    }
+
+    #[test]
+    fn should_not_loop_over_cycles() {
+        // given
+        let first = Rc::new(BasicGraphNode::new(1, Item(false), None));
+        let second = Rc::new(BasicGraphNode::new(
             2,
             Item(false),
-            Some(vec![Rc::clone(&four), Rc::clone(&five)]),
-        ));
-        let one = Rc::new(BasicGraphNode::new(
-            1,
-            Item(false),
-            Some(vec![Rc::clone(&two), Rc::clone(&three)]),
+            Some(vec![Rc::clone(&first)]),
         ));
 
-        graph.insert(eight);
-        graph.insert(seven);
-        graph.insert(six);
-        graph.insert(five);
-        graph.insert(four);
-        graph.insert(three);
-        graph.insert(two);
-        graph.insert(one);
+        first.nodes().borrow_mut().push(Rc::clone(&second));
+
+        // then
+        assert!(breadth_first_search(&first, |x| x.value().0).is_none());
+        assert_eq!(
+            &1,
+            breadth_first_search(&second, |x| *x.id() == 1)
+                .unwrap()
+                .id()
+        );
+
+        // Nodes point to each other, so we break the cycle to drop them
+        first.nodes().borrow_mut().clear();
+    }
+
+    #[test]
+    fn should_not_find_match_beyond_max_depth() {
+        let graph = get_graph();
+
+        // 7 is two edges away from 1
+        assert!(breadth_first_search_limited(1, &graph, |x| x.0, 1).is_none());
+        assert_eq!(
+            &7,
+            breadth_first_search_limited(1, &graph, |x| x.0, 2)
+                .unwrap()
+                .id()
+        );
+        assert_eq!(
+            &7,
+            breadth_first_search_limited(3, &graph, |x| x.0, 1)
+                .unwrap()
+                .id()
+        );
+        assert!(breadth_first_search_limited(7, &graph, |x| x.0, 5).is_none());
+        assert!(breadth_first_search_limited(9, &graph, |x| x.0, 5).is_none());
+    }
+
+    #[test]
+    fn should_visit_nodes_in_bfs_order() {
+        // given
+        let graph = get_graph();
+        let mut visited = vec![];
+        let mut nodes_per_level = vec![0; 4];
+
+        // when
+        breadth_first_search_visit(1, &graph, |id, depth| {
+            visited.push((*id, depth));
+            nodes_per_level[depth] += 1;
+        });
+
+        // then
+        assert_eq!(
+            vec![
+                (1, 0),
+                (2, 1),
+                (3, 1),
+                (4, 2),
+                (5, 2),
+                (6, 2),
+                (7, 2),
+                (8, 3)
+            ],
+            visited
+        );
+        assert_eq!(vec![1, 2, 4, 1], nodes_per_level);
+    }
+
+    #[test]
+    fn should_not_visit_anything_if_start_does_not_exist() {
+        let mut visited = vec![];
+
+        breadth_first_search_visit(10, &get_graph(), |id, _| visited.push(*id));
+
+        assert!(visited.is_empty());
+    }
+
+    #[test]
+    fn should_find_only_reachable_node_by_id() {
+        // given
+        let mut graph = get_graph();
+
+        graph.insert(Rc::new(BasicGraphNode::new(9, Item(false), None)));
+
+        // then
+        assert_eq!(&8, find_by_id(1, &graph, &8).unwrap().id());
+        assert_eq!(&3, find_by_id(3, &graph, &3).unwrap().id());
+        assert!(find_by_id(1, &graph, &9).is_none());
+        assert!(find_by_id(6, &graph, &7).is_none());
+        assert!(find_by_id(10, &graph, &1).is_none());
+        assert!(graph.contains(&9));
+    }
+
+    #[test]
+    fn should_calculate_distances() {
+        let distances = bfs_distances(1, &get_graph());
+
+        assert_eq!(
+            HashMap::from([
+                (1, 0),
+                (2, 1),
+                (3, 1),
+                (4, 2),
+                (5, 2),
+                (6, 2),
+                (7, 2),
+                (8, 3)
+            ]),
+            distances
+        );
+        assert_eq!(
+            HashMap::from([(6, 0), (8, 1)]),
+            bfs_distances(6, &get_graph())
+        );
+        assert!(bfs_distances(9, &get_graph()).is_empty());
+    }
+
+    #[test]
+    fn should_find_reachable_sinks() {
+        let graph = get_graph();
+
+        assert_eq!(vec![4, 5, 7, 8], reachable_sinks(&graph, 1));
+        assert_eq!(vec![8], reachable_sinks(&graph, 6));
+        assert_eq!(vec![5], reachable_sinks(&graph, 5));
+        assert!(reachable_sinks(&graph, 9).is_empty());
+    }
+
+    #[test]
+    fn should_find_shortest_path() {
+        let graph = get_graph();
 
-        assert_eq!(&7, breadth_first_search(1, &graph, |x| x.0).unwrap().id())
+        assert_eq!(Some(vec![1, 3, 6, 8]), shortest_path(&graph, 1, 8));
+        assert_eq!(Some(vec![2]), shortest_path(&graph, 2, 2));
+        assert_eq!(None, shortest_path(&graph, 2, 8));
+        assert_eq!(None, shortest_path(&graph, 1, 9));
     }
 
     #[test]
@@ -126,6 +491,6 @@ mod tests {
         graph.insert(three);
         graph.insert(two);
 
-        assert!(breadth_first_search(1, &graph, |x| x.0).is_none());
+        assert!(breadth_first_search(graph.get(&1).unwrap(), |x| x.value().0).is_none());
     }
 }
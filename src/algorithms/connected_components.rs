@@ -0,0 +1,130 @@
+use crate::graph::{BasicGraph, GraphNode};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/// # Description
+/// Splits a graph into connected components, i.e. groups of nodes where any node can be reached from any other node of the group
+/// if we ignore directions of edges(treat the graph as undirected). Such components are also called "weakly connected".
+///
+/// Realisation details:
+/// 1. Build undirected adjacency lists, i.e. for every edge `A -> B` we remember both `A -> B` and `B -> A`.
+/// 2. Take any not yet visited node and run breadth first traversal from it. Everything it reaches is one component.
+/// 3. Repeat 2nd step till all nodes are visited.
+///
+/// Edges to nodes which are not a part of the `graph` are skipped.
+///
+/// # Complexity
+/// `O(n + e)`, where `n` is a number of nodes and `e` is a number of edges.
+pub fn connected_components<T, K>(graph: &BasicGraph<T, K>) -> Vec<Vec<K>>
+where
+    K: Eq + Hash + Clone,
+{
+    let mut neighbors: HashMap<K, Vec<K>> = graph
+        .nodes()
+        .map(|node| (node.id().clone(), vec![]))
+        .collect();
+
+    for node in graph.nodes() {
+        for child in node.nodes().borrow().iter() {
+            if !neighbors.contains_key(child.id()) {
+                continue;
+            }
+
+            neighbors
+                .entry(node.id().clone())
+                .or_default()
+                .push(child.id().clone());
+            neighbors
+                .entry(child.id().clone())
+                .or_default()
+                .push(node.id().clone());
+        }
+    }
+
+    let mut visited = HashSet::with_capacity(neighbors.len());
+    let mut components = vec![];
+
+    for id in neighbors.keys() {
+        if visited.contains(id) {
+            continue;
+        }
+
+        let mut component = vec![];
+        let mut queue = VecDeque::from([id]);
+
+        visited.insert(id.clone());
+
+        while let Some(id) = queue.pop_front() {
+            component.push(id.clone());
+
+            for neighbor in &neighbors[id] {
+                if visited.insert(neighbor.clone()) {
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        components.push(component);
+    }
+
+    components
+}
+
+/// Returns `true` if every node of a graph can be reached from any other node when directions of edges are ignored,
+/// i.e. the graph has exactly one connected component(see `connected_components`).
+///
+/// **Note** that an empty graph has no components at all, so it's not connected.
+pub fn is_connected<T, K>(graph: &BasicGraph<T, K>) -> bool
+where
+    K: Eq + Hash + Clone,
+{
+    connected_components(graph).len() == 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{connected_components, is_connected};
+    use crate::graph::{BasicGraph, BasicGraphNode, Graph};
+    use std::rc::Rc;
+
+    fn get_graph() -> BasicGraph<()> {
+        let mut graph = BasicGraph::new();
+
+        for id in 1..=5 {
+            graph.insert(Rc::new(BasicGraphNode::new(id, (), None)));
+        }
+
+        graph.connect(1, 2);
+        graph.connect(3, 2);
+        graph.connect(4, 5);
+
+        graph
+    }
+
+    #[test]
+    fn should_find_components() {
+        let mut components = connected_components(&get_graph());
+
+        components
+            .iter_mut()
+            .for_each(|component| component.sort_unstable());
+        components.sort_unstable();
+
+        assert_eq!(vec![vec![1, 2, 3], vec![4, 5]], components);
+    }
+
+    #[test]
+    fn should_be_connected() {
+        let mut graph = get_graph();
+
+        graph.connect(5, 3);
+
+        assert!(is_connected(&graph));
+    }
+
+    #[test]
+    fn should_not_be_connected() {
+        assert!(!is_connected(&get_graph()));
+        assert!(!is_connected(&BasicGraph::<()>::new()));
+    }
+}
@@ -1,9 +1,13 @@
-use crate::tree::{Tree, TreeNode};
+use crate::tree::{BasicTree, Tree, TreeNode};
+use crate::Traversable;
+use std::collections::HashSet;
+use std::fmt::Debug;
+use std::hash::Hash;
 use std::rc::Rc;
 
 /// # Description
 ///
-/// This is traversal algorithm, which means that we recursively go through all nodes in whole tree.
+/// This is traversal algorithm, which means that we go through all nodes in whole tree branch by branch.
 /// So the difference from breadth first search algorithm is that in BFS we search by layers, but in DFS we search branch by branch.
 /// In DFS - If desired node is in first level, but in the last branch, then we won't get it until we check all branches before the last one.
 /// In BFS - If desired node in the first branch, but it is the latest node, then we won't get it until we check ALL layers, which means all elements
@@ -17,42 +21,137 @@ use std::rc::Rc;
 /// * trees are more conservative, tree's nodes can have only one parent, and they cannot have cycles(nodes can't point to parents)
 ///
 /// So there is no faster algorithm between DFS and BFS, it depends on details.
-pub fn depth_first_search<T, N, K, V, P>(tree: &T, predicate: P) -> Option<Rc<N>>
+///
+/// It works over any `Traversable` nodes, the same as `breadth_first_search`, so it runs over trees(e.g. `tree.head()` as the start node) and graphs.
+/// Unlike `breadth_first_search`, the start node is checked too. Trees don't have cycles, but graphs may have them,
+/// so visited nodes are remembered by their addresses(`Rc::as_ptr`) and every node is checked once.
+///
+/// Nodes which are not checked yet are kept in a `Vec` instead of recursion, so long chains don't overflow the call stack.
+/// Children are pushed in reversed order, so the first child is on top and branches are checked in the same order as recursion would do.
+pub fn depth_first_search<N, P>(start_node: &Rc<N>, predicate: P) -> Option<Rc<N>>
+where
+    N: Traversable,
+    P: Fn(&N) -> bool,
+{
+    let mut visited = HashSet::new();
+    let mut stack = vec![Rc::clone(start_node)];
+
+    while let Some(node) = stack.pop() {
+        if !visited.insert(Rc::as_ptr(&node)) {
+            continue;
+        }
+
+        if predicate(&node) {
+            return Some(node);
+        }
+
+        stack.extend(node.children().into_iter().rev());
+    }
+
+    None
+}
+
+/// # Description
+///
+/// Iterative deepening DFS runs DFS which doesn't go deeper than a limit, first with limit `0`(the head only), then with limit `1` and so on till `max_depth`.
+/// Nodes on upper levels are checked again on every run, but a tree grows by levels, so most of nodes are on the deepest level, and it doesn't cost much.
+///
+/// It combines advantages of both searches:
+/// * like DFS, it keeps in memory only one branch at a time(a stack of nodes with their depths), instead of a whole layer as BFS does.
+/// * like BFS, it finds the closest to the head match, as all nodes on a level are checked before any node on the next level.
+///
+/// So it's handy when a match is likely close to the head, but some branches are very deep.
+/// Returns `None` if there is no match within `max_depth` levels(the head is on level `0`).
+///
+/// # Complexity
+/// `O(n * d)` in the worst case(e.g. a tree which is a single long branch), where `n` is a number of nodes within `max_depth` levels and `d` is `max_depth`.
+/// For wide trees it's close to `O(n)`, as the last level takes most of the time.
+pub fn iterative_deepening_dfs<T, N, K, V, P>(
+    tree: &T,
+    predicate: P,
+    max_depth: usize,
+) -> Option<Rc<N>>
 where
     N: TreeNode<V, K>,
     T: Tree<N, V, K>,
     P: Fn(&N) -> bool,
 {
-    fn search<N, V, K, P>(node: &Rc<N>, predicate: &P) -> Option<Rc<N>>
-    where
-        N: TreeNode<V, K>,
-        P: Fn(&N) -> bool,
-    {
-        if predicate(node) {
-            return Some(Rc::clone(node));
-        }
+    (0..=max_depth).find_map(|depth_limit| {
+        let mut stack = vec![(Rc::clone(tree.head()), 0)];
 
-        for node in node.nodes().borrow().iter() {
-            let search_result = search(node, predicate);
+        while let Some((node, depth)) = stack.pop() {
+            if predicate(&node) {
+                return Some(node);
+            }
 
-            if search_result.is_some() {
-                return search_result;
+            if depth < depth_limit {
+                // Reversed, so children are checked in the order they were inserted
+                stack.extend(
+                    node.nodes()
+                        .borrow()
+                        .iter()
+                        .rev()
+                        .map(|child| (Rc::clone(child), depth + 1)),
+                );
             }
         }
 
         None
+    })
+}
+
+/// # Description
+/// Returns every path of ids from the head to a leaf(a node without children), e.g. to enumerate all outcomes of a decision tree.
+/// Paths go in DFS order: branches are taken in the order children were inserted. A tree of one node has one path of the head only.
+///
+/// We go depth first and keep ids of the current branch in a path: an id is pushed when we go down to a node, and when we go to another branch,
+/// the path is cut back to a parent of the next node, so when we reach a leaf, the path is exactly the way from the head to it.
+/// Nodes to visit are kept in a `Vec` with a length of the path to them instead of recursion, so long branches don't overflow the call stack.
+///
+/// # Complexity
+/// `O(n * h)`, where `n` is a number of nodes and `h` is a height of the tree, as every path is copied to the result.
+pub fn root_to_leaf_paths<V, K>(tree: &BasicTree<V, K>) -> Vec<Vec<K>>
+where
+    K: Eq + Hash + Clone + Debug,
+{
+    let mut paths = vec![];
+    let mut path = vec![];
+    // Every node keeps a length of the path to its parent, so when we take it, the path is cut back to its branch
+    let mut stack = vec![(Rc::clone(tree.head()), 0)];
+
+    while let Some((node, path_len)) = stack.pop() {
+        path.truncate(path_len);
+        path.push(node.id().clone());
+
+        let children = node.nodes().borrow();
+
+        if children.is_empty() {
+            paths.push(path.clone());
+        }
+
+        // Reversed, so the first child is on top and paths go in the order children were inserted
+        stack.extend(
+            children
+                .iter()
+                .rev()
+                .map(|child| (Rc::clone(child), path.len())),
+        );
     }
 
-    search(tree.head(), &predicate)
+    paths
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::algorithms::depth_first_search::depth_first_search;
-    use crate::tree::{BasicTree, TreeNode};
+    use crate::algorithms::depth_first_search::{
+        depth_first_search, iterative_deepening_dfs, root_to_leaf_paths,
+    };
+    use crate::graph::{BasicGraph, BasicGraphNode, Graph, GraphNode};
+    use crate::tree::{BasicTree, Tree, TreeNode};
+    use std::cell::RefCell;
+    use std::rc::Rc;
 
-    #[test]
-    fn should_find_shortest() {
+    fn get_tree() -> BasicTree<bool> {
         let mut tree = BasicTree::from_head(1, false);
 
         tree.insert(2, 1, false);
@@ -63,7 +162,217 @@ mod tests {
         tree.insert(7, 3, true);
         tree.insert(8, 6, false);
 
-        assert_eq!(&7, depth_first_search(&tree, |x| *x.value()).unwrap().id())
+        tree
+    }
+
+    #[test]
+    fn should_find_shortest() {
+        let tree = get_tree();
+
+        assert_eq!(
+            &7,
+            depth_first_search(tree.head(), |x| *x.value())
+                .unwrap()
+                .id()
+        )
+    }
+
+    #[test]
+    fn should_collect_root_to_leaf_paths() {
+        assert_eq!(
+            vec![
+                vec![1, 2, 4],
+                vec![1, 2, 5],
+                vec![1, 3, 6, 8],
+                vec![1, 3, 7]
+            ],
+            root_to_leaf_paths(&get_tree())
+        );
+        assert_eq!(
+            vec![vec!["head"]],
+            root_to_leaf_paths(&BasicTree::from_head("head", ()))
+        );
+    }
+
+    #[test]
+    fn should_collect_path_of_long_branch() {
+        // given
+        let mut tree = BasicTree::from_head(0, ());
+
+        for id in 1..100_000 {
+            tree.insert(id, id - 1, ());
+        }
+
+        // when
+        let paths = root_to_leaf_paths(&tree);
+
+        // then
+        assert_eq!(vec![(0..100_000).collect::<Vec<_>>()], paths);
+
+        // Dropping the head would drop the whole branch recursively, so we break it first
+        for id in 0..100_000 {
+            tree.get(&id).unwrap().nodes().borrow_mut().clear();
+        }
+    }
+
+    #[test]
+    fn should_navigate_to_siblings_and_children_by_value() {
+        // given
+        let tree = get_tree();
+        let three = tree.get(&3).unwrap();
+
+        // then
+        let siblings: Vec<i32> = tree
+            .get(&6)
+            .unwrap()
+            .siblings()
+            .iter()
+            .map(|x| *x.id())
+            .collect();
+
+        assert_eq!(vec![7], siblings);
+        assert_eq!(&2, three.siblings()[0].id());
+        assert!(tree.head().siblings().is_empty());
+        assert!(tree.get(&8).unwrap().siblings().is_empty());
+
+        assert_eq!(&7, three.child_with_value(&true).unwrap().id());
+        assert_eq!(&6, three.child_with_value(&false).unwrap().id());
+        assert!(tree.get(&8).unwrap().child_with_value(&false).is_none());
+    }
+
+    #[test]
+    fn should_search_graph_and_tree_through_the_same_interface() {
+        // given
+        let mut tree = BasicTree::from_head(1, false);
+        let graph = BasicGraph::from_adjacency(
+            (1..=5).map(|id| (id, id == 5)).collect(),
+            &[(1, 2), (1, 3), (2, 4), (3, 4), (4, 5)],
+        );
+
+        tree.insert(2, 1, false);
+        tree.insert(3, 1, true);
+        tree.insert(4, 2, true);
+
+        let checked_nodes = RefCell::new(vec![]);
+
+        // when
+        let found = depth_first_search(graph.get(&1).unwrap(), |x| {
+            checked_nodes.borrow_mut().push(*x.id());
+            *x.value()
+        });
+
+        // then
+        // A branch goes first, so 4 is found before 3 in the tree
+        assert_eq!(
+            &4,
+            depth_first_search(tree.head(), |x| *x.value())
+                .unwrap()
+                .id()
+        );
+        assert_eq!(&5, found.unwrap().id());
+        // 4 is reachable via 2 and 3, but it's checked once
+        assert_eq!(vec![1, 2, 4, 5], *checked_nodes.borrow());
+        assert_eq!(
+            &1,
+            depth_first_search(tree.head(), |x| !*x.value())
+                .unwrap()
+                .id()
+        );
+    }
+
+    #[test]
+    fn should_not_overflow_stack_on_long_chain() {
+        // given
+        let graph = BasicGraph::from_adjacency(
+            (0..100_000).map(|id| (id, id == 99_999)).collect(),
+            &(1..100_000).map(|id| (id - 1, id)).collect::<Vec<_>>(),
+        );
+
+        // when
+        let found = depth_first_search(graph.get(&0).unwrap(), |x| *x.value());
+
+        // then
+        assert_eq!(&99_999, found.unwrap().id());
+
+        // Dropping the head would drop the whole chain recursively, so we break edges first
+        for node in graph.nodes() {
+            node.nodes().borrow_mut().clear();
+        }
+    }
+
+    #[test]
+    fn should_check_every_node_of_a_cycle_once() {
+        // given
+        let first = Rc::new(BasicGraphNode::new(1, 0, None));
+        let second = Rc::new(BasicGraphNode::new(2, 0, Some(vec![Rc::clone(&first)])));
+        let checked_count = RefCell::new(0);
+
+        first.nodes().borrow_mut().push(Rc::clone(&second));
+
+        // when
+        let found = depth_first_search(&first, |_| {
+            *checked_count.borrow_mut() += 1;
+            false
+        });
+
+        // then
+        assert!(found.is_none());
+        assert_eq!(2, *checked_count.borrow());
+
+        // Nodes point to each other, so we break the cycle to drop them
+        first.nodes().borrow_mut().clear();
+    }
+
+    #[test]
+    fn should_find_shallow_match_before_deep_branch() {
+        // given
+        let mut tree = BasicTree::from_head(1, false);
+
+        // The first branch is deep and has a match at the bottom, the second branch has a match right under the head
+        tree.insert(2, 1, false);
+        tree.insert(3, 2, false);
+        tree.insert(4, 3, false);
+        tree.insert(5, 4, true);
+        tree.insert(6, 1, true);
+
+        let checked_nodes = RefCell::new(vec![]);
+
+        // when
+        let found = iterative_deepening_dfs(
+            &tree,
+            |x| {
+                checked_nodes.borrow_mut().push(*x.id());
+                *x.value()
+            },
+            10,
+        );
+
+        // then
+        assert_eq!(
+            &5,
+            depth_first_search(tree.head(), |x| *x.value())
+                .unwrap()
+                .id()
+        );
+        assert_eq!(&6, found.unwrap().id());
+        assert!(!checked_nodes.borrow().contains(&4));
+        assert!(!checked_nodes.borrow().contains(&5));
+    }
+
+    #[test]
+    fn should_not_go_deeper_than_max_depth() {
+        let mut tree = BasicTree::from_head(1, false);
+
+        tree.insert(2, 1, false);
+        tree.insert(3, 2, true);
+
+        assert!(iterative_deepening_dfs(&tree, |x| *x.value(), 1).is_none());
+        assert_eq!(
+            &3,
+            iterative_deepening_dfs(&tree, |x| *x.value(), 2)
+                .unwrap()
+                .id()
+        );
     }
 
     #[test]
@@ -73,6 +382,6 @@ mod tests {
         tree.insert(2, 1, false);
         tree.insert(3, 1, false);
 
-        assert!(depth_first_search(&tree, |x| *x.value()).is_none());
+        assert!(depth_first_search(tree.head(), |x| *x.value()).is_none());
     }
 }
@@ -1,65 +1,96 @@
-use crate::weighted_graph::{WeightedGraph, WeightedGraphNode};
+use crate::sync_weighted_graph::SyncWeightedGraph;
+use crate::weighted_graph::WeightedGraph;
+use crate::IndexedMinHeap;
 use std::collections::hash_map::Entry;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::hash::Hash;
-use std::rc::Rc;
+use std::slice;
 
-// TODO: The book mentioned that it's better to use "Priority Queue" data structure for that.
-//  I have some ideas what that might be, but it's better to learn "Priority Queue" and get back here than guessing.
-//  Also it seems Rust has std::collections::BinaryHeap which is a "Priority Queue", but I'd like to figure out by myself how to implement it and then use existed solution.
-fn get_lowest<K>(cost: &HashMap<K, i32>, finish: &K) -> Option<K>
+/// Gives the search access to outgoing edges of nodes, so the same search works for both `WeightedGraph` and `SyncWeightedGraph`.
+trait Edges<K> {
+    /// Calls `visit` with an id and a weight of every outgoing edge of `node_id`, the node must exist.
+    fn for_each_edge<E: FnMut(&K, i32)>(&self, node_id: &K, visit: E);
+}
+
+impl<K> Edges<K> for WeightedGraph<K>
 where
-    K: Ord + Hash + Copy + Eq,
+    K: Ord + Hash + Clone + Eq,
 {
-    let lowest = cost
-        .iter()
-        .reduce(|acc, item| if item.1 < acc.1 { item } else { acc })?;
-
-    if lowest.0 == finish {
-        return None;
+    fn for_each_edge<E: FnMut(&K, i32)>(&self, node_id: &K, mut visit: E) {
+        for edge in self.get(node_id).unwrap().nodes().iter() {
+            visit(edge.node().id(), edge.weight());
+        }
     }
+}
 
-    Some(*lowest.0)
+impl<K> Edges<K> for SyncWeightedGraph<K>
+where
+    K: Ord + Hash + Clone + Eq,
+{
+    fn for_each_edge<E: FnMut(&K, i32)>(&self, node_id: &K, mut visit: E) {
+        for edge in self.get(node_id).unwrap().nodes().iter() {
+            visit(edge.node().id(), edge.weight());
+        }
+    }
 }
 
-fn calculate_cost<K>(
-    node: &Rc<WeightedGraphNode<K>>,
+/// Updates costs of children of `node_id`. Costs are summed with `saturating_add`, so a path longer than `i32::MAX` costs `i32::MAX`
+/// instead of overflowing, it means "effectively unreachable": any real(not saturated) path is cheaper.
+fn calculate_cost<K, G, F>(
+    graph: &G,
+    node_id: &K,
     cost: &mut HashMap<K, i32>,
     parents: &mut HashMap<K, K>,
+    frontier: &mut IndexedMinHeap<K, i32>,
+    visited: &HashSet<K>,
+    is_edge_allowed: &F,
 ) where
-    K: Ord + Hash + Copy + Eq,
+    K: Ord + Hash + Clone + Eq,
+    G: Edges<K>,
+    F: Fn(&K, &K) -> bool,
 {
-    let current_node_cost = *cost.get(&node.id()).unwrap_or(&0);
+    let current_node_cost = cost[node_id];
+
+    graph.for_each_edge(node_id, |child_id, weight| {
+        // Cost of a visited node is final, so it can't be updated and the node can't get back to the frontier
+        if visited.contains(child_id) || !is_edge_allowed(node_id, child_id) {
+            return;
+        }
 
-    for child in node.nodes().iter() {
-        let new_cost_to_child = current_node_cost + child.weight();
+        let new_cost_to_child = current_node_cost.saturating_add(weight);
 
-        match cost.entry(child.node().id()) {
+        match cost.entry(child_id.clone()) {
             Entry::Occupied(current_min_cost_to_child) => {
                 if &new_cost_to_child < current_min_cost_to_child.get() {
                     *current_min_cost_to_child.into_mut() = new_cost_to_child;
-                    parents
-                        .entry(child.node().id())
-                        .and_modify(|entry| *entry = node.id());
+                    // The cheaper path goes through the current node, so it's the new parent regardless of what was there before.
+                    // `and_modify` would silently skip a child without a parent entry(e.g. the start node), so we always insert.
+                    parents.insert(child_id.clone(), node_id.clone());
+
+                    // The child is usually still waiting in the frontier, so we only lower its priority
+                    if !frontier.decrease_key(child_id, new_cost_to_child) {
+                        frontier.push(child_id.clone(), new_cost_to_child);
+                    }
                 }
             }
             Entry::Vacant(current_min_cost_to_child) => {
                 current_min_cost_to_child.insert(new_cost_to_child);
-                parents.insert(child.node().id(), node.id());
+                parents.insert(child_id.clone(), node_id.clone());
+                frontier.push(child_id.clone(), new_cost_to_child);
             }
         }
-    }
+    });
 }
 
-fn build_chain<K>(finish: K, parents: &HashMap<K, K>) -> Vec<K>
+pub(crate) fn build_chain<K>(finish: K, parents: &HashMap<K, K>) -> Vec<K>
 where
-    K: Ord + Hash + Copy + Eq,
+    K: Hash + Clone + Eq,
 {
-    let mut chain = vec![finish];
     let mut next_parent = parents.get(&finish);
+    let mut chain = vec![finish.clone()];
 
     while let Some(parent) = next_parent {
-        chain.push(*parent);
+        chain.push(parent.clone());
         next_parent = parents.get(parent);
     }
 
@@ -67,6 +98,83 @@ where
     chain
 }
 
+/// Returns costs and parents of all visited nodes. If `finish` is `None`, then we visit all reachable nodes.
+/// All `starts` cost `0`, so with several of them every node gets a path from the nearest one.
+fn find_costs_and_parents<K, G, F>(
+    graph: &G,
+    starts: &[K],
+    finish: Option<&K>,
+    is_edge_allowed: &F,
+) -> (HashMap<K, i32>, HashMap<K, K>)
+where
+    K: Ord + Hash + Clone + Eq,
+    G: Edges<K>,
+    F: Fn(&K, &K) -> bool,
+{
+    let mut cost = HashMap::new();
+    let mut parents = HashMap::new();
+    let mut frontier = IndexedMinHeap::new();
+    let mut visited = HashSet::new();
+
+    for start in starts {
+        cost.insert(start.clone(), 0);
+        frontier.push(start.clone(), 0);
+    }
+
+    // We take the cheapest node from the frontier and calculate its children cost till we reach finish
+    while let Some((lowest, _)) = frontier.pop() {
+        if Some(&lowest) == finish {
+            break;
+        }
+
+        visited.insert(lowest.clone());
+
+        calculate_cost(
+            graph,
+            &lowest,
+            &mut cost,
+            &mut parents,
+            &mut frontier,
+            &visited,
+            is_edge_allowed,
+        );
+    }
+
+    (cost, parents)
+}
+
+/// The same as `dijkstra_search`, but it goes only through edges for which `is_edge_allowed(from, to)` returns `true`.
+/// Returns `None` if `start` does not exist or `finish` can't be reached.
+pub(crate) fn dijkstra_search_filtered<K, F>(
+    graph: &WeightedGraph<K>,
+    start: &K,
+    finish: &K,
+    is_edge_allowed: F,
+) -> Option<Vec<K>>
+where
+    K: Ord + Hash + Clone + Eq,
+    F: Fn(&K, &K) -> bool,
+{
+    graph.get(start)?;
+
+    if start == finish {
+        return Some(vec![start.clone()]);
+    }
+
+    let (_, parents) = find_costs_and_parents(
+        graph,
+        slice::from_ref(start),
+        Some(finish),
+        &is_edge_allowed,
+    );
+
+    if !parents.contains_key(finish) {
+        return None;
+    }
+
+    Some(build_chain(finish.clone(), &parents))
+}
+
 /// # Description
 ///
 /// Dijkstra search algorithm is an approach to find the shortest path from A to B in a graph.
@@ -74,48 +182,192 @@ where
 /// That's it, besides that they are similar as they both have `O(n)` complexity in general as we have to went through all nodes.
 ///
 /// Realisation details:
-/// 1. Find a node with the lowest cost(a weight to get to the node). In the beginning we need to calculate s cost from start node to its children.
-/// 2. Then We take the cheapest node(a node with the lowest cost) and calculate cost to its children(the same way as we did with the start node).
+/// 1. Find a node with the lowest cost(a weight to get to the node). In the beginning it's the start node with cost `0`.
+/// 2. Then We take the cheapest node(a node with the lowest cost) and calculate cost to its children.
 /// 3. If new cost from the current node to a child is lower than existing cost(e.g. there was another path to the child, but more expensive), then we update the child's cost and its parent.
-/// 3. When cost to children is calculated - we drop a node from `cost` `HashMap` as we don't need it anymore, we found cost to its children already.
-/// 4. Repeat 1-3 steps till the lowest node is the `finish` node. That means we reached the end of our graph and visited all nodes.
-/// 5. Build a chain from the start to the finish using `parents` `HashMap`.
+/// 4. Nodes which cost is calculated, but which children are not checked yet, are kept in a "frontier" priority queue(`IndexedMinHeap`),
+///    so the cheapest node is always on top and taking it is `O(log n)` instead of checking all nodes.
+///    When a cheaper path to a node already in the frontier is found, its priority is lowered in place.
+/// 5. Repeat 1-4 steps till the lowest node is the `finish` node or the frontier is empty.
+/// 6. Build a chain from the start to the finish using `parents` `HashMap`.
+///
+/// Costs never overflow: they are capped at `i32::MAX`, which means "effectively unreachable". A path which costs more than that
+/// is still found if there is no other path, but all such paths cost the same, so any of them may be taken.
+///
+/// # Complexity
+/// `O((n + e) log n)`, where `n` is a number of nodes and `e` is a number of edges.
 #[allow(clippy::missing_panics_doc)]
 pub fn dijkstra_search<K>(graph: &WeightedGraph<K>, start: K, finish: K) -> Vec<K>
 where
-    K: Ord + Hash + Copy + Eq,
+    K: Ord + Hash + Clone + Eq,
 {
-    let mut cost: HashMap<K, i32> = HashMap::new();
-    let mut parents = HashMap::new();
+    let (_, parents) = find_costs_and_parents(
+        graph,
+        slice::from_ref(&start),
+        Some(&finish),
+        &|_: &K, _: &K| true,
+    );
 
-    // Here we need to get cost to start's children
-    calculate_cost(graph.get(&start).unwrap(), &mut cost, &mut parents);
+    build_chain(finish, &parents)
+}
 
-    // Then we get the cheapest node and calculate its children cost till we reach finish(get_lowest returns None if current lowest is finish node)
-    while let Some(lowest) = get_lowest(&cost, &finish) {
-        calculate_cost(graph.get(&lowest).unwrap(), &mut cost, &mut parents);
-        // Remove node from cost HashMap when we're done with it.
-        cost.remove(&lowest);
-    }
+/// The same as `dijkstra_search`, but for a `SyncWeightedGraph`, so several threads can search in one shared graph at the same time.
+/// Edges of a node are locked for reading only while the node is processed, so threads don't block each other.
+///
+/// # Complexity
+/// `O((n + e) log n)`, where `n` is a number of nodes and `e` is a number of edges.
+#[allow(clippy::missing_panics_doc)]
+pub fn dijkstra_search_sync<K>(graph: &SyncWeightedGraph<K>, start: K, finish: K) -> Vec<K>
+where
+    K: Ord + Hash + Clone + Eq,
+{
+    let (_, parents) = find_costs_and_parents(
+        graph,
+        slice::from_ref(&start),
+        Some(&finish),
+        &|_: &K, _: &K| true,
+    );
 
     build_chain(finish, &parents)
 }
 
+/// The same as `dijkstra_search`, but the search starts from all `sources` at once(all of them cost `0`),
+/// so it returns the shortest path from the nearest source to `finish`, e.g. a path from the nearest facility.
+/// Sources which don't exist in the graph are ignored.
+///
+/// Returns `None` if none of `sources` exists or `finish` can't be reached.
+///
+/// # Complexity
+/// `O((n + e) log n)`, the same as for one source, as every node is still processed once.
+#[must_use]
+pub fn dijkstra_multi_source<K>(
+    graph: &WeightedGraph<K>,
+    sources: &[K],
+    finish: K,
+) -> Option<Vec<K>>
+where
+    K: Ord + Hash + Clone + Eq,
+{
+    let sources: Vec<K> = sources
+        .iter()
+        .filter(|source| graph.contains(source))
+        .cloned()
+        .collect();
+
+    let (cost, parents) =
+        find_costs_and_parents(graph, &sources, Some(&finish), &|_: &K, _: &K| true);
+
+    // A reached finish always has cost, but it has no parent if it's one of sources
+    if !cost.contains_key(&finish) {
+        return None;
+    }
+
+    Some(build_chain(finish, &parents))
+}
+
+/// Returns the shortest distance from `start` to every reachable node(including `start` itself with `0`) in one run of Dijkstra search.
+/// It's the same search as `dijkstra_search`, but it doesn't stop at a finish node, so all nodes are taken from the frontier and their cost is final.
+///
+/// Returns an empty `HashMap` if `start` does not exist. Nodes which can be reached only by paths longer than `i32::MAX` get `i32::MAX`.
+///
+/// # Complexity
+/// `O((n + e) log n)`, where `n` is a number of nodes and `e` is a number of edges.
+#[must_use]
+pub fn dijkstra_all_distances<K>(graph: &WeightedGraph<K>, start: K) -> HashMap<K, i32>
+where
+    K: Ord + Hash + Clone + Eq,
+{
+    if graph.get(&start).is_none() {
+        return HashMap::new();
+    }
+
+    let (cost, _) =
+        find_costs_and_parents(graph, slice::from_ref(&start), None, &|_: &K, _: &K| true);
+
+    cost
+}
+
+/// Returns a shortest-path tree from `start`: every reachable node(except `start`) is mapped to its parent on the shortest path from `start`.
+/// To get a path to any node, follow parents from the node till `start` and reverse the chain, there is no need to run the search again.
+///
+/// Returns an empty `HashMap` if `start` does not exist or has no reachable nodes.
+///
+/// # Complexity
+/// `O((n + e) log n)`, where `n` is a number of nodes and `e` is a number of edges.
+#[must_use]
+pub fn dijkstra_tree<K>(graph: &WeightedGraph<K>, start: K) -> HashMap<K, K>
+where
+    K: Ord + Hash + Clone + Eq,
+{
+    if graph.get(&start).is_none() {
+        return HashMap::new();
+    }
+
+    let (_, parents) =
+        find_costs_and_parents(graph, slice::from_ref(&start), None, &|_: &K, _: &K| true);
+
+    parents
+}
+
+/// # Description
+/// Answers "can `finish` be reached from `start`" ignoring weights. It's a plain depth first traversal with a `Vec` as a stack,
+/// so it doesn't need a priority queue, costs and parents as `dijkstra_search` does, and it stops as soon as `finish` is reached.
+///
+/// A node is always reachable from itself. Returns `false` if either node does not exist.
+///
+/// # Complexity
+/// `O(n + e)`, where `n` is a number of nodes and `e` is a number of edges.
+#[must_use]
+pub fn has_path<K>(graph: &WeightedGraph<K>, start: K, finish: K) -> bool
+where
+    K: Ord + Hash + Clone + Eq,
+{
+    if !graph.contains(&start) || !graph.contains(&finish) {
+        return false;
+    }
+
+    let mut visited = HashSet::from([start.clone()]);
+    let mut stack = vec![start];
+
+    while let Some(node_id) = stack.pop() {
+        if node_id == finish {
+            return true;
+        }
+
+        graph.for_each_edge(&node_id, |child_id, _| {
+            if visited.insert(child_id.clone()) {
+                stack.push(child_id.clone());
+            }
+        });
+    }
+
+    false
+}
+
 #[cfg(test)]
 mod tests {
-    use super::dijkstra_search;
+    use super::{
+        build_chain, dijkstra_all_distances, dijkstra_multi_source, dijkstra_search,
+        dijkstra_search_filtered, dijkstra_search_sync, dijkstra_tree, has_path,
+    };
+    use crate::graph::{Graph, GraphError};
+    use crate::shortest_path;
+    use crate::sync_weighted_graph::SyncWeightedGraph;
     use crate::weighted_graph::WeightedGraph;
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use std::thread;
 
-    #[test]
-    fn should_find_shortest_path() {
-        // given
+    const BOOK: &str = "book";
+    const DISK: &str = "disk";
+    const POSTER: &str = "poster";
+    const DRUMS: &str = "drums";
+    const GUITAR: &str = "guitar";
+    const PIANO: &str = "piano";
+
+    fn get_graph() -> WeightedGraph<&'static str> {
         let mut graph = WeightedGraph::new();
-        const BOOK: &str = "book";
-        const DISK: &str = "disk";
-        const POSTER: &str = "poster";
-        const DRUMS: &str = "drums";
-        const GUITAR: &str = "guitar";
-        const PIANO: &str = "piano";
 
         graph.insert(BOOK);
         graph.insert(DISK);
@@ -124,6 +376,41 @@ mod tests {
         graph.insert(GUITAR);
         graph.insert(PIANO);
 
+        graph.connect_all(&[
+            (BOOK, DISK, 5),
+            (BOOK, POSTER, 0),
+            (DISK, GUITAR, 15),
+            (DISK, DRUMS, 20),
+            (POSTER, GUITAR, 30),
+            (POSTER, DRUMS, 35),
+            (GUITAR, PIANO, 20),
+            (DRUMS, PIANO, 10),
+        ]);
+
+        graph
+    }
+
+    #[test]
+    fn should_find_shortest_path() {
+        // given
+        let graph = get_graph();
+
+        // when
+        let shortest_path = dijkstra_search(&graph, BOOK, PIANO);
+
+        // then
+        assert_eq!(vec![BOOK, DISK, DRUMS, PIANO], shortest_path);
+    }
+
+    #[test]
+    fn should_find_shortest_paths_from_different_threads() {
+        // given
+        let mut graph = SyncWeightedGraph::new();
+
+        for node in [BOOK, DISK, POSTER, DRUMS, GUITAR, PIANO] {
+            graph.insert(node);
+        }
+
         graph.connect(BOOK, DISK, 5);
         graph.connect(BOOK, POSTER, 0);
         graph.connect(DISK, GUITAR, 15);
@@ -133,10 +420,325 @@ mod tests {
         graph.connect(GUITAR, PIANO, 20);
         graph.connect(DRUMS, PIANO, 10);
 
+        let graph = Arc::new(graph);
+
         // when
+        let to_piano = {
+            let graph = Arc::clone(&graph);
+
+            thread::spawn(move || dijkstra_search_sync(&graph, BOOK, PIANO))
+        };
+        let to_guitar = {
+            let graph = Arc::clone(&graph);
+
+            thread::spawn(move || dijkstra_search_sync(&graph, POSTER, GUITAR))
+        };
+
+        // then
+        assert_eq!(vec![BOOK, DISK, DRUMS, PIANO], to_piano.join().unwrap());
+        assert_eq!(vec![POSTER, GUITAR], to_guitar.join().unwrap());
+    }
+
+    #[test]
+    fn should_find_path_with_fewest_edges_in_unweighted_copy() {
+        // given
+        let graph = get_graph();
+
+        // when
+        let unweighted = graph.to_unweighted();
+
+        // then
+        assert_eq!(6, unweighted.len());
+        // Weights are dropped, so the path goes through guitar, which is connected to disk first
+        assert_eq!(
+            Some(vec![BOOK, DISK, GUITAR, PIANO]),
+            shortest_path(&unweighted, BOOK, PIANO)
+        );
+        assert_eq!(None, shortest_path(&unweighted, PIANO, BOOK));
+    }
+
+    #[test]
+    fn should_iterate_over_all_nodes() {
+        // given
+        let graph = get_graph();
+        let mut ids = vec![];
+
+        // when
+        for node in &graph {
+            ids.push(*node.id());
+        }
+
+        // then
+        ids.sort_unstable();
+
+        assert_eq!(vec![BOOK, DISK, DRUMS, GUITAR, PIANO, POSTER], ids);
+    }
+
+    #[test]
+    fn should_list_outgoing_edges() {
+        let graph = get_graph();
+
+        assert_eq!(Some(vec![(DISK, 5), (POSTER, 0)]), graph.edges_from(&BOOK));
+        assert_eq!(Some(vec![]), graph.edges_from(&PIANO));
+    }
+
+    #[test]
+    fn should_check_path_existence() {
+        // given
+        let mut graph = get_graph();
+
+        // A separate component which is not connected to the fixture at all
+        graph.insert("lamp");
+        graph.insert("table");
+        graph.connect("lamp", "table", 1);
+
+        // then
+        assert!(has_path(&graph, BOOK, PIANO));
+        assert!(has_path(&graph, "lamp", "table"));
+        assert!(has_path(&graph, PIANO, PIANO));
+        assert!(!has_path(&graph, PIANO, BOOK));
+        assert!(!has_path(&graph, BOOK, "table"));
+        assert!(!has_path(&graph, "lamp", PIANO));
+        assert!(!has_path(&graph, BOOK, "chair"));
+    }
+
+    #[test]
+    fn should_not_overflow_on_huge_weights() {
+        // given
+        let mut graph = WeightedGraph::new();
+
+        for id in 1..=5 {
+            graph.insert(id);
+        }
+
+        // 1 -> 2 -> 3 is longer than `i32::MAX`, 1 -> 4 -> 3 is short, and 5 is reachable only via a huge path
+        graph.connect_all(&[
+            (1, 2, i32::MAX - 10),
+            (2, 3, i32::MAX - 10),
+            (2, 5, i32::MAX - 10),
+            (1, 4, 5),
+            (4, 3, 5),
+        ]);
+
+        // when
+        let distances = dijkstra_all_distances(&graph, 1);
+
+        // then
+        assert_eq!(vec![1, 4, 3], dijkstra_search(&graph, 1, 3));
+        assert_eq!(vec![1, 2, 5], dijkstra_search(&graph, 1, 5));
+        assert_eq!(10, distances[&3]);
+        assert_eq!(i32::MAX - 10, distances[&2]);
+        assert_eq!(i32::MAX, distances[&5]);
+    }
+
+    #[test]
+    fn should_use_updated_edge_weight() {
+        // given
+        let mut graph = get_graph();
+
+        assert_eq!(
+            vec![BOOK, DISK, DRUMS, PIANO],
+            dijkstra_search(&graph, BOOK, PIANO)
+        );
+
+        // when
+        // DISK -> DRUMS gets more expensive than DISK -> GUITAR -> PIANO
+        let result = graph.update_edge_weight(DISK, DRUMS, 40);
+
+        // then
+        assert_eq!(Ok(()), result);
+        assert_eq!(
+            Some(vec![(GUITAR, 15), (DRUMS, 40)]),
+            graph.edges_from(&DISK)
+        );
+        assert_eq!(
+            vec![BOOK, DISK, GUITAR, PIANO],
+            dijkstra_search(&graph, BOOK, PIANO)
+        );
+        assert_eq!(
+            Err(GraphError::EdgeNotFound {
+                from: PIANO,
+                to: BOOK
+            }),
+            graph.update_edge_weight(PIANO, BOOK, 1)
+        );
+        assert_eq!(
+            Err(GraphError::FromNodeNotFound("chair")),
+            graph.update_edge_weight("chair", BOOK, 1)
+        );
+        assert_eq!(
+            Err(GraphError::ToNodeNotFound("chair")),
+            graph.update_edge_weight(BOOK, "chair", 1)
+        );
+    }
+
+    #[test]
+    fn should_find_min_and_max_edges() {
+        let mut graph = get_graph();
+
+        assert_eq!(Some((BOOK, POSTER, 0)), graph.min_edge());
+        assert_eq!(Some((POSTER, DRUMS, 35)), graph.max_edge());
+        assert_eq!(8, graph.edges().len());
+
+        // Ties are resolved by ids, not by the order of nodes in the `HashMap`
+        graph.connect(GUITAR, DISK, 35);
+
+        assert_eq!(Some((GUITAR, DISK, 35)), graph.max_edge());
+        assert_eq!(None, WeightedGraph::<i32>::new().min_edge());
+    }
+
+    #[test]
+    fn should_match_path_weight_of_shortest_path() {
+        let graph = get_graph();
         let shortest_path = dijkstra_search(&graph, BOOK, PIANO);
 
+        assert_eq!(Some(35), graph.path_weight(&shortest_path));
+        assert_eq!(Some(50), graph.path_weight(&[BOOK, POSTER, GUITAR, PIANO]));
+        assert_eq!(None, graph.path_weight(&[BOOK, DRUMS, PIANO]));
+    }
+
+    #[test]
+    fn should_find_distances_to_all_nodes() {
+        // given
+        let graph = get_graph();
+
+        // when
+        let distances = dijkstra_all_distances(&graph, BOOK);
+
         // then
-        assert_eq!(vec![BOOK, DISK, DRUMS, PIANO], shortest_path);
+        assert_eq!(
+            HashMap::from([
+                (BOOK, 0),
+                (DISK, 5),
+                (POSTER, 0),
+                (GUITAR, 20),
+                (DRUMS, 25),
+                (PIANO, 35),
+            ]),
+            distances
+        );
+        assert_eq!(
+            HashMap::from([(PIANO, 0)]),
+            dijkstra_all_distances(&graph, PIANO)
+        );
+        assert!(dijkstra_all_distances(&graph, "unknown").is_empty());
+    }
+
+    #[test]
+    fn should_build_paths_to_different_nodes_from_one_tree() {
+        // given
+        let graph = get_graph();
+
+        // when
+        let tree = dijkstra_tree(&graph, BOOK);
+
+        // then
+        assert_eq!(5, tree.len());
+        assert_eq!(vec![BOOK, DISK, DRUMS, PIANO], build_chain(PIANO, &tree));
+        assert_eq!(vec![BOOK, DISK, GUITAR], build_chain(GUITAR, &tree));
+        assert!(dijkstra_tree(&graph, PIANO).is_empty());
+    }
+
+    #[test]
+    fn should_update_parent_when_cheaper_path_is_found_later() {
+        // given
+        let mut graph = WeightedGraph::new();
+
+        for id in 1..=4 {
+            graph.insert(id);
+        }
+
+        // 4 is reached from 1 first with cost 10, the cheaper path 1 -> 2 -> 3 -> 4 with cost 3 is found later
+        graph.connect(1, 4, 10);
+        graph.connect(1, 2, 1);
+        graph.connect(2, 3, 1);
+        graph.connect(3, 4, 1);
+
+        // when
+        let shortest_path = dijkstra_search(&graph, 1, 4);
+        let tree = dijkstra_tree(&graph, 1);
+
+        // then
+        assert_eq!(vec![1, 2, 3, 4], shortest_path);
+        assert_eq!(Some(&3), tree.get(&4));
+        assert_eq!(Some(&3), dijkstra_all_distances(&graph, 1).get(&4));
+    }
+
+    #[test]
+    fn should_process_every_node_once() {
+        // given
+        let mut graph = WeightedGraph::new();
+
+        for id in 1..=4 {
+            graph.insert(id);
+        }
+
+        // 2 is taken from the frontier first with cost 1, the negative edge from 3 makes a "cheaper" path to 2 after it's already visited
+        graph.connect(1, 2, 1);
+        graph.connect(1, 3, 5);
+        graph.connect(3, 2, -10);
+        graph.connect(2, 4, 1);
+
+        let processed_edges = RefCell::new(vec![]);
+
+        // when
+        let shortest_path = dijkstra_search_filtered(&graph, &1, &5, |from, to| {
+            processed_edges.borrow_mut().push((*from, *to));
+            true
+        });
+
+        // then
+        assert_eq!(None, shortest_path);
+        assert_eq!(
+            1,
+            processed_edges
+                .borrow()
+                .iter()
+                .filter(|edge| **edge == (2, 4))
+                .count()
+        );
+        assert_eq!(Some(&1), dijkstra_all_distances(&graph, 1).get(&2));
+    }
+
+    #[test]
+    fn should_find_path_from_nearest_source() {
+        // given
+        let graph = get_graph();
+
+        // when
+        let from_both = dijkstra_multi_source(&graph, &[BOOK, GUITAR], PIANO);
+        let from_book = dijkstra_multi_source(&graph, &[BOOK, "unknown"], PIANO);
+
+        // then
+        assert_eq!(Some(vec![GUITAR, PIANO]), from_both);
+        assert_eq!(Some(vec![BOOK, DISK, DRUMS, PIANO]), from_book);
+        assert_eq!(
+            Some(vec![DRUMS]),
+            dijkstra_multi_source(&graph, &[BOOK, DRUMS], DRUMS)
+        );
+        assert_eq!(None, dijkstra_multi_source(&graph, &[PIANO], BOOK));
+        assert_eq!(None, dijkstra_multi_source(&graph, &[], BOOK));
+    }
+
+    #[test]
+    fn should_find_shortest_path_with_string_ids() {
+        // given
+        let mut graph: WeightedGraph<String> = WeightedGraph::new();
+
+        for id in ["start", "a", "b", "finish"] {
+            graph.insert(id.to_string());
+        }
+
+        graph.connect("start".to_string(), "a".to_string(), 6);
+        graph.connect("start".to_string(), "b".to_string(), 2);
+        graph.connect("b".to_string(), "a".to_string(), 3);
+        graph.connect("a".to_string(), "finish".to_string(), 1);
+        graph.connect("b".to_string(), "finish".to_string(), 5);
+
+        // when
+        let shortest_path = dijkstra_search(&graph, "start".to_string(), "finish".to_string());
+
+        // then
+        assert_eq!(vec!["start", "b", "a", "finish"], shortest_path);
     }
 }
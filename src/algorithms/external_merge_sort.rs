@@ -0,0 +1,143 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::vec::IntoIter;
+
+/// The head of a sorted run, i.e. the lowest element of the run which is not taken yet.
+struct RunHead<T> {
+    value: T,
+    run: usize,
+}
+
+impl<T> Eq for RunHead<T> where T: Ord {}
+
+impl<T> PartialEq<Self> for RunHead<T>
+where
+    T: Ord,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T> PartialOrd<Self> for RunHead<T>
+where
+    T: Ord,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for RunHead<T>
+where
+    T: Ord,
+{
+    // `BinaryHeap` is a max-heap, so the order is reversed to get the lowest head first.
+    // Equal values are taken from earlier runs first, so equal elements keep their original order.
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .value
+            .cmp(&self.value)
+            .then_with(|| other.run.cmp(&self.run))
+    }
+}
+
+struct KWayMerge<T> {
+    runs: Vec<IntoIter<T>>,
+    heads: BinaryHeap<RunHead<T>>,
+}
+
+impl<T> Iterator for KWayMerge<T>
+where
+    T: Ord,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let RunHead { value, run } = self.heads.pop()?;
+
+        if let Some(next_value) = self.runs[run].next() {
+            self.heads.push(RunHead {
+                value: next_value,
+                run,
+            });
+        }
+
+        Some(value)
+    }
+}
+
+/// # Description
+/// External merge sort is used when there is too much data to sort it at once, so instead:
+/// 1. We read `chunk_size` elements at a time, sort them and keep them as a sorted "run".
+///    In a real external sort runs are written to disk, here they are kept as separate vectors, but the idea is the same -
+///    we never sort more than `chunk_size` elements at once.
+/// 2. Then we merge all runs together(k-way merge). We keep the lowest not yet taken element of every run in a priority queue(`BinaryHeap`),
+///    so the next element of the result is always on top of the queue. When we take it, we put the next element of the same run into the queue.
+///
+/// The result is a lazy iterator, so merged elements are produced one by one, which allows to stream them somewhere else without collecting.
+/// The sort is stable.
+///
+/// # Complexity
+/// `O(n log c)` for sorting runs, where `c` is `chunk_size`, and `O(n log k)` for merging, where `k` is a number of runs.
+///
+/// # Panics
+/// Panics if `chunk_size` is `0`.
+pub fn external_merge_sort<T, I>(iter: I, chunk_size: usize) -> impl Iterator<Item = T>
+where
+    T: Ord,
+    I: Iterator<Item = T>,
+{
+    assert!(chunk_size > 0, "\"chunk_size\" must be bigger than 0");
+
+    let mut runs = vec![];
+    let mut iter = iter.peekable();
+
+    while iter.peek().is_some() {
+        let mut run = iter.by_ref().take(chunk_size).collect::<Vec<_>>();
+
+        run.sort();
+        runs.push(run.into_iter());
+    }
+
+    let mut heads = BinaryHeap::with_capacity(runs.len());
+
+    for (run, values) in runs.iter_mut().enumerate() {
+        if let Some(value) = values.next() {
+            heads.push(RunHead { value, run });
+        }
+    }
+
+    KWayMerge { runs, heads }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::external_merge_sort;
+
+    #[test]
+    fn should_sort_long_iterator() {
+        // given
+        let values = (0..1000).map(|x| (x * 7919) % 1009).collect::<Vec<i32>>();
+        let mut expected = values.clone();
+        expected.sort_unstable();
+
+        // when
+        let sorted = external_merge_sort(values.into_iter(), 16).collect::<Vec<_>>();
+
+        // then
+        assert_eq!(expected, sorted);
+    }
+
+    #[test]
+    fn should_sort_when_chunk_is_bigger_than_input() {
+        let sorted = external_merge_sort([3, 1, 2].into_iter(), 10).collect::<Vec<_>>();
+
+        assert_eq!(vec![1, 2, 3], sorted);
+    }
+
+    #[test]
+    fn should_return_nothing_for_empty_iterator() {
+        assert_eq!(0, external_merge_sort(std::iter::empty::<i32>(), 4).count());
+    }
+}
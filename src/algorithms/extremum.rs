@@ -0,0 +1,52 @@
+use crate::algorithms::Order;
+
+/// # Description
+/// Returns the lowest(`Order::Asc`) or the biggest(`Order::Desc`) element of `slice`, i.e. the element which goes first after sorting in this order,
+/// or `None` if `slice` is empty. If there are several equal extremums, then the first one is returned.
+///
+/// We keep the best element so far and replace it only with a strictly better one, that's why the first of equal elements stays.
+///
+/// # Complexity
+/// `O(n)`, it's a single pass without additional memory.
+#[must_use]
+pub fn extremum<T: Ord>(slice: &[T], order: Order) -> Option<&T> {
+    let (first, rest) = slice.split_first()?;
+
+    let found = rest.iter().fold(first, |best, item| match order {
+        Order::Asc if item < best => item,
+        Order::Desc if item > best => item,
+        _ => best,
+    });
+
+    Some(found)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::extremum;
+    use crate::algorithms::Order;
+    use std::ptr;
+
+    #[test]
+    fn should_find_min_and_max() {
+        let list = [4, -2, 9, 0, 9, -2, 3];
+
+        assert_eq!(Some(&-2), extremum(&list, Order::Asc));
+        assert_eq!(Some(&9), extremum(&list, Order::Desc));
+        assert_eq!(Some(&7), extremum(&[7], Order::Desc));
+    }
+
+    #[test]
+    fn should_return_first_of_equal_extremums() {
+        let list = [1, 3, 1, 3];
+
+        assert!(ptr::eq(&list[0], extremum(&list, Order::Asc).unwrap()));
+        assert!(ptr::eq(&list[1], extremum(&list, Order::Desc).unwrap()));
+    }
+
+    #[test]
+    fn should_return_none_for_empty_slice() {
+        assert_eq!(None, extremum::<i32>(&[], Order::Asc));
+        assert_eq!(None, extremum::<i32>(&[], Order::Desc));
+    }
+}
@@ -0,0 +1,128 @@
+use crate::graph::{BasicGraph, BasicGraphNode, Graph, GraphNode};
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+use std::rc::Rc;
+
+/// # Description
+/// Finds a cycle in a directed graph and returns ids of its nodes in the order of edges, e.g. `[A, B, C]` for `A -> B -> C -> A`.
+/// A node which points to itself is a cycle of one node. Returns `None` if the graph has no cycles(it's a DAG).
+/// It's handy to debug data which was expected to be a DAG, as it shows which edges make the cycle, not only that it exists.
+///
+/// Realisation details:
+/// 1. Go depth first from every not yet finished node, a node is finished when all its children are done.
+/// 2. Nodes of the current branch are kept in a stack, and every node remembers its position in the stack.
+/// 3. An edge to a node which is in the current branch is a back edge: the part of the stack from that node to the top is the cycle.
+/// 4. An edge to a finished node is skipped, there are no cycles through it, otherwise we would have found them already.
+///
+/// Nodes are started in order of their ids and children are taken in the order of edges, so the result is the same for the same graph.
+/// The stack is a `Vec` instead of recursion, so long chains don't overflow the call stack.
+/// Edges to nodes which are not a part of the `graph` are skipped.
+///
+/// # Complexity
+/// `O(n log n + e)`, where `n` is a number of nodes and `e` is a number of edges, `n log n` is for sorting ids.
+pub fn find_cycle<T, K>(graph: &BasicGraph<T, K>) -> Option<Vec<K>>
+where
+    K: Ord + Hash + Clone,
+{
+    let mut ids: Vec<&K> = graph.nodes().map(|node| node.id()).collect();
+    let mut finished = HashSet::with_capacity(ids.len());
+    // A position of every node of the current branch in the `branch` stack
+    let mut positions: HashMap<K, usize> = HashMap::new();
+
+    ids.sort_unstable();
+
+    for id in ids {
+        if finished.contains(id) {
+            continue;
+        }
+
+        // Every node keeps an index of its next child to check
+        let mut branch: Vec<(&Rc<BasicGraphNode<T, K>>, usize)> = vec![(graph.get(id)?, 0)];
+
+        positions.insert(id.clone(), 0);
+
+        while let Some((node, next_child)) = branch.last_mut() {
+            let child_id = node
+                .nodes()
+                .borrow()
+                .get(*next_child)
+                .map(|child| child.id().clone());
+
+            *next_child += 1;
+
+            let Some(child_id) = child_id else {
+                positions.remove(node.id());
+                finished.insert(node.id().clone());
+                branch.pop();
+                continue;
+            };
+
+            if let Some(&position) = positions.get(&child_id) {
+                return Some(
+                    branch[position..]
+                        .iter()
+                        .map(|(node, _)| node.id().clone())
+                        .collect(),
+                );
+            }
+
+            if finished.contains(&child_id) {
+                continue;
+            }
+
+            if let Some(child) = graph.get(&child_id) {
+                positions.insert(child_id, branch.len());
+                branch.push((child, 0));
+            }
+        }
+    }
+
+    None
+}
+
+/// Returns `true` if a directed graph has at least one cycle, see `find_cycle`.
+pub fn has_cycle<T, K>(graph: &BasicGraph<T, K>) -> bool
+where
+    K: Ord + Hash + Clone,
+{
+    find_cycle(graph).is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_cycle, has_cycle};
+    use crate::graph::BasicGraph;
+
+    #[test]
+    fn should_find_cycle_path() {
+        // given
+        // 2 -> 3 -> 4 -> 2 is the cycle, 1 and 5 lead to it, but are not a part of it
+        let graph = BasicGraph::from_adjacency(
+            (1..=6).map(|id| (id, ())).collect(),
+            &[(1, 2), (2, 3), (3, 6), (3, 4), (4, 2), (5, 4)],
+        );
+
+        // then
+        assert_eq!(Some(vec![2, 3, 4]), find_cycle(&graph));
+        assert!(has_cycle(&graph));
+    }
+
+    #[test]
+    fn should_not_find_cycle_in_dag() {
+        // Two paths lead to 4, but it's not a cycle
+        let graph = BasicGraph::from_adjacency(
+            (1..=4).map(|id| (id, ())).collect(),
+            &[(1, 2), (1, 3), (2, 4), (3, 4)],
+        );
+
+        assert_eq!(None, find_cycle(&graph));
+        assert!(!has_cycle(&BasicGraph::<(), i32>::new()));
+    }
+
+    #[test]
+    fn should_find_self_loop() {
+        let graph = BasicGraph::from_adjacency(vec![(1, ()), (2, ())], &[(1, 2), (2, 2)]);
+
+        assert_eq!(Some(vec![2]), find_cycle(&graph));
+    }
+}
@@ -0,0 +1,150 @@
+/// # Description
+/// Turns an arbitrary slice into a max-heap in place: every parent is bigger or equal to its children, so the maximum is at `0` index.
+/// Children of an item at `index` are at `2 * index + 1` and `2 * index + 2`, so the heap is a binary tree stored in a slice without any pointers.
+///
+/// # Complexity
+/// `O(n)`. It may look like `O(n log n)` as we call `sift_down` for half of items, but most of them are close to the bottom and go down just a few levels.
+///
+/// Realisation details:
+/// 1. Leaves(the second half of the slice) are already valid heaps of one item.
+/// 2. Go through parents from the last one to the first one and sift every parent down, so after each step a subtree of this parent is a valid heap.
+pub fn heapify<T>(slice: &mut [T])
+where
+    T: Ord,
+{
+    for index in (0..slice.len() / 2).rev() {
+        sift_down(slice, index);
+    }
+}
+
+/// Moves an item at `index` down till it's bigger or equal to both its children, children of the item must be valid heaps already.
+/// On every step the item is swapped with its biggest child, so the child becomes a valid parent for both sides.
+///
+/// # Complexity
+/// `O(log n)`
+pub fn sift_down<T>(slice: &mut [T], index: usize)
+where
+    T: Ord,
+{
+    let mut index = index;
+
+    loop {
+        let left = 2 * index + 1;
+        let right = left + 1;
+        let mut biggest = index;
+
+        if left < slice.len() && slice[left] > slice[biggest] {
+            biggest = left;
+        }
+
+        if right < slice.len() && slice[right] > slice[biggest] {
+            biggest = right;
+        }
+
+        if biggest == index {
+            break;
+        }
+
+        slice.swap(index, biggest);
+        index = biggest;
+    }
+}
+
+/// Moves an item at `index` up till its parent is bigger or equal to it, e.g. after the item was pushed to the end of a heap.
+///
+/// # Complexity
+/// `O(log n)`
+pub fn sift_up<T>(slice: &mut [T], index: usize)
+where
+    T: Ord,
+{
+    let mut index = index;
+
+    while index > 0 {
+        let parent = (index - 1) / 2;
+
+        if slice[parent] >= slice[index] {
+            break;
+        }
+
+        slice.swap(parent, index);
+        index = parent;
+    }
+}
+
+/// Checks that a slice is a valid max-heap, i.e. every item is bigger or equal to its children at `2 * index + 1` and `2 * index + 2`.
+/// Every item except the first one has exactly one parent, so we compare every item with its parent at `(index - 1) / 2` instead.
+/// Empty and single item slices are valid heaps. It's handy as a check of `heapify`, `sift_down` and `sift_up` results.
+///
+/// # Complexity
+/// `O(n)`
+#[must_use]
+pub fn is_heap<T: Ord>(slice: &[T]) -> bool {
+    (1..slice.len()).all(|index| slice[(index - 1) / 2] >= slice[index])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{heapify, is_heap, sift_down, sift_up};
+
+    #[test]
+    fn should_check_heap_property() {
+        assert!(is_heap(&[9, 7, 8, 3, 7, 1, 2]));
+        assert!(is_heap(&[1, 1, 1]));
+        // 4 is bigger than its parent 3
+        assert!(!is_heap(&[9, 3, 8, 1, 4]));
+        assert!(!is_heap(&[1, 2]));
+        assert!(is_heap(&[5]));
+        assert!(is_heap::<i32>(&[]));
+    }
+
+    #[test]
+    fn should_heapify_slice() {
+        // given
+        let mut list = vec![3, 9, 2, 1, 4, 5, 8, 7, 6, 0, 9];
+
+        // when
+        heapify(&mut list);
+
+        // then
+        assert_eq!(9, list[0]);
+        assert!(is_heap(&list));
+    }
+
+    #[test]
+    fn should_keep_heap_after_sifting() {
+        // given
+        let mut list = vec![5, 1, 8, 3, 2];
+
+        heapify(&mut list);
+
+        // when
+        list.push(10);
+        let last = list.len() - 1;
+        sift_up(&mut list, last);
+
+        // then
+        assert_eq!(10, list[0]);
+        assert!(is_heap(&list));
+
+        // when
+        list[0] = 0;
+        sift_down(&mut list, 0);
+
+        // then
+        assert_eq!(8, list[0]);
+        assert!(is_heap(&list));
+    }
+
+    #[test]
+    fn should_heapify_empty_and_single_item_slices() {
+        let mut empty: Vec<i32> = vec![];
+        let mut single = vec![1];
+
+        heapify(&mut empty);
+        heapify(&mut single);
+
+        assert!(empty.is_empty());
+        assert_eq!(vec![1], single);
+    }
+}
@@ -1,17 +1,31 @@
+use std::cmp::Ordering;
+
 /// # Description
 /// Insertion sort has O(n^2) time complexity, which makes it very slow sorting algorithm.
 /// However, its constant time is usually faster than merge sort. So it makes sense to use insertion sort for small input
-/// and other(merge sort/quick sort) for big input.
+/// and other(merge sort/quick sort) for big input. That's what `merge_sort` does, see `merge_sort_with_cutoff`.
 #[allow(dead_code)]
 pub fn insertion_sort<T>(arr: &mut [T])
 where
     T: PartialOrd + Copy,
+{
+    insertion_sort_by(arr, |first, second| {
+        first.partial_cmp(second).unwrap_or(Ordering::Equal)
+    });
+}
+
+/// The same as `insertion_sort`, but elements are compared via `cmp`, so `merge_sort` can use the same comparison for both sorts.
+/// An element is moved only over strictly bigger ones, so the sort is stable.
+pub fn insertion_sort_by<T, F>(arr: &mut [T], cmp: F)
+where
+    T: Copy,
+    F: Fn(&T, &T) -> Ordering,
 {
     for i in 1..arr.len() {
         let key = arr[i];
         let mut j = i - 1;
 
-        while j != usize::MAX && arr[j] > key {
+        while j != usize::MAX && cmp(&arr[j], &key) == Ordering::Greater {
             arr[j + 1] = arr[j];
             j = j.wrapping_sub(1);
         }
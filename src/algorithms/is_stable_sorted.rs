@@ -0,0 +1,83 @@
+/// # Description
+/// Checks that `sorted` is `original` sorted by `key` stably: items go in ascending order of keys, and items with equal keys
+/// keep the same relative order as in `original`. It's an oracle for testing stable sorts(e.g. `merge_sort_by` or `tim_sort`).
+///
+/// There is exactly one stable arrangement of `original`, so we build it with the standard library stable sort and compare.
+/// It also means that `sorted` must have the same items as `original`, a lost or changed item makes it `false`.
+///
+/// # Complexity
+/// `O(n log n)` time and `O(n)` additional space.
+pub fn is_stable_sorted<T, K, F>(original: &[T], sorted: &[T], key: F) -> bool
+where
+    T: Eq,
+    K: Ord,
+    F: Fn(&T) -> K,
+{
+    if original.len() != sorted.len() {
+        return false;
+    }
+
+    let mut expected: Vec<&T> = original.iter().collect();
+
+    expected.sort_by_key(|item| key(item));
+
+    expected.into_iter().eq(sorted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_stable_sorted;
+    use crate::merge_sort_by;
+
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    struct Record {
+        name: &'static str,
+        age: u8,
+    }
+
+    fn get_records() -> Vec<Record> {
+        [
+            ("ann", 30),
+            ("bob", 25),
+            ("cid", 30),
+            ("dan", 25),
+            ("eve", 20),
+        ]
+        .into_iter()
+        .map(|(name, age)| Record { name, age })
+        .collect()
+    }
+
+    #[test]
+    fn should_accept_stable_arrangement() {
+        // given
+        let records = get_records();
+        let mut sorted = records.clone();
+
+        // when
+        merge_sort_by(&mut sorted, |a, b| a.age.cmp(&b.age));
+
+        // then
+        assert!(is_stable_sorted(&records, &sorted, |record| record.age));
+        assert!(is_stable_sorted::<Record, u8, _>(&[], &[], |record| record.age));
+    }
+
+    #[test]
+    fn should_reject_unstable_arrangement() {
+        // given
+        let records = get_records();
+        // Sorted by age, but "dan" went before "bob"
+        let mut unstable = records.clone();
+
+        unstable.sort_by_key(|record| record.age);
+        unstable.swap(1, 2);
+
+        // then
+        assert_eq!(unstable[1].age, unstable[2].age);
+        assert!(!is_stable_sorted(&records, &unstable, |record| record.age));
+        // Not sorted at all
+        assert!(!is_stable_sorted(&records, &records, |record| record.age));
+        // A lost item
+        assert!(!is_stable_sorted(&records, &unstable[1..], |record| record.age));
+    }
+}
@@ -1,5 +1,7 @@
 use std::cmp::Ordering;
 use std::collections::{BinaryHeap, HashMap};
+use std::num::NonZeroUsize;
+use std::thread;
 
 #[allow(dead_code)]
 pub trait Neighbor {
@@ -15,7 +17,7 @@ impl<'a> Eq for NeighborWithDistance<'a> {}
 
 impl<'a> PartialEq<Self> for NeighborWithDistance<'a> {
     fn eq(&self, other: &Self) -> bool {
-        self.distance == other.distance
+        self.cmp(other) == Ordering::Equal
     }
 }
 
@@ -27,17 +29,40 @@ impl<'a> PartialOrd<Self> for NeighborWithDistance<'a> {
 
 impl<'a> Ord for NeighborWithDistance<'a> {
     fn cmp(&self, other: &Self) -> Ordering {
-        if self.distance > other.distance {
+        let by_distance = if self.distance > other.distance {
             Ordering::Greater
         } else if self.distance < other.distance {
             Ordering::Less
         } else {
             Ordering::Equal
+        };
+
+        // Neighbors with the same distance are ordered by name, otherwise which of them is taken would depend on `HashMap` order
+        by_distance.then_with(|| self.neighbor_name.cmp(other.neighbor_name))
+    }
+}
+
+/// Keeps `neighbors_count` nearest neighbors in a max-heap: the farthest of them is on top, so a nearer neighbor replaces it.
+fn push_nearest<'a>(
+    priority_queue: &mut BinaryHeap<NeighborWithDistance<'a>>,
+    next_neighbor: NeighborWithDistance<'a>,
+    neighbors_count: usize,
+) {
+    if priority_queue.len() < neighbors_count {
+        priority_queue.push(next_neighbor);
+    } else if let Some(mut biggest_item) = priority_queue.peek_mut() {
+        if *biggest_item > next_neighbor {
+            *biggest_item = next_neighbor;
         }
     }
 }
 
-// TODO: Docs
+/// Returns names of `neighbors_count` nearest to `item_name` neighbors(`item_name` itself is excluded), the nearest goes first.
+/// Neighbors with the same distance are taken in order of their names.
+///
+/// # Panics
+///
+/// Panics if `item_name` is not in `neighbors`.
 #[allow(dead_code)]
 pub fn k_nearest_neighbor<'a, T: Neighbor>(
     neighbors: &'a HashMap<&str, T>,
@@ -58,17 +83,79 @@ pub fn k_nearest_neighbor<'a, T: Neighbor>(
             distance: neighbor.calculate_neighbor_distance(item),
         };
 
-        if priority_queue.len() == neighbors_count {
-            let mut biggest_item = priority_queue.peek_mut().unwrap();
-            if biggest_item.distance > next_neighbor.distance {
-                *biggest_item = next_neighbor;
-            }
-        } else {
-            priority_queue.push(next_neighbor);
-        }
+        push_nearest(&mut priority_queue, next_neighbor, neighbors_count);
+    }
+
+    priority_queue
+        .into_sorted_vec()
+        .into_iter()
+        .map(|neighbor| neighbor.neighbor_name)
+        .collect()
+}
+
+/// # Description
+/// The same as `k_nearest_neighbor`, but distances are calculated in several threads, it's useful when calculating a distance is expensive.
+/// The result is the same as in `k_nearest_neighbor`.
+///
+/// Realisation details:
+/// 1. Split neighbors into chunks, one chunk per available CPU.
+/// 2. Every thread finds `neighbors_count` nearest neighbors of its chunk in its own heap.
+/// 3. The overall nearest neighbors are among the nearest of every chunk, so we merge the partial heaps into one the same way.
+///
+/// # Panics
+///
+/// Panics if `item_name` is not in `neighbors`.
+#[allow(dead_code)]
+pub fn k_nearest_neighbor_parallel<'a, T: Neighbor + Sync>(
+    neighbors: &'a HashMap<&str, T>,
+    item_name: &'static str,
+    neighbors_count: usize,
+) -> Vec<&'a str> {
+    let item = neighbors.get(item_name).unwrap();
+    let candidates: Vec<(&str, &T)> = neighbors
+        .iter()
+        .filter(|(&name, _)| name != item_name)
+        .map(|(&name, neighbor)| (name, neighbor))
+        .collect();
+
+    let workers_count = thread::available_parallelism().map_or(1, NonZeroUsize::get);
+    let chunk_size = candidates.len().div_ceil(workers_count).max(1);
+
+    let partial_queues: Vec<BinaryHeap<NeighborWithDistance>> = thread::scope(|scope| {
+        let workers: Vec<_> = candidates
+            .chunks(chunk_size)
+            .map(|chunk| {
+                scope.spawn(move || {
+                    let mut priority_queue = BinaryHeap::with_capacity(neighbors_count);
+
+                    for &(name, neighbor) in chunk {
+                        let next_neighbor = NeighborWithDistance {
+                            neighbor_name: name,
+                            distance: neighbor.calculate_neighbor_distance(item),
+                        };
+
+                        push_nearest(&mut priority_queue, next_neighbor, neighbors_count);
+                    }
+
+                    priority_queue
+                })
+            })
+            .collect();
+
+        workers
+            .into_iter()
+            .map(|worker| worker.join().unwrap())
+            .collect()
+    });
+
+    let mut priority_queue = BinaryHeap::with_capacity(neighbors_count);
+
+    for next_neighbor in partial_queues.into_iter().flatten() {
+        push_nearest(&mut priority_queue, next_neighbor, neighbors_count);
     }
 
     priority_queue
+        .into_sorted_vec()
         .into_iter()
         .map(|neighbor| neighbor.neighbor_name)
         .collect()
@@ -76,7 +163,7 @@ pub fn k_nearest_neighbor<'a, T: Neighbor>(
 
 #[cfg(test)]
 mod tests {
-    use super::{k_nearest_neighbor, Neighbor};
+    use super::{k_nearest_neighbor, k_nearest_neighbor_parallel, Neighbor};
     use std::collections::HashMap;
 
     struct Preferences {
@@ -88,6 +175,7 @@ mod tests {
     }
 
     struct Viewer<'a> {
+        #[allow(dead_code)]
         name: &'a str,
         preferences: Preferences,
     }
@@ -117,15 +205,15 @@ mod tests {
     impl<'a> Neighbor for Viewer<'a> {
         fn calculate_neighbor_distance(&self, another_neighbor: &Self) -> f64 {
             let comedy_difference =
-                (&self.preferences.comedy - another_neighbor.preferences.comedy).pow(2);
+                (self.preferences.comedy - another_neighbor.preferences.comedy).pow(2);
             let action_difference =
-                (&self.preferences.action - another_neighbor.preferences.action).pow(2);
+                (self.preferences.action - another_neighbor.preferences.action).pow(2);
             let drama_difference =
-                (&self.preferences.drama - another_neighbor.preferences.drama).pow(2);
+                (self.preferences.drama - another_neighbor.preferences.drama).pow(2);
             let horror_difference =
-                (&self.preferences.horror - another_neighbor.preferences.horror).pow(2);
+                (self.preferences.horror - another_neighbor.preferences.horror).pow(2);
             let romance_difference =
-                (&self.preferences.romance - another_neighbor.preferences.romance).pow(2);
+                (self.preferences.romance - another_neighbor.preferences.romance).pow(2);
 
             ((comedy_difference
                 + action_difference
@@ -163,4 +251,37 @@ mod tests {
         assert!(three_nearest_neighbors.contains(&"jared"));
         assert!(three_nearest_neighbors.contains(&"cristy"));
     }
+    #[test]
+    fn should_find_the_same_nearest_in_parallel() {
+        // given
+        let names: Vec<String> = (0..500).map(|index| format!("viewer-{index}")).collect();
+        let mut neighbors = HashMap::with_capacity(names.len() + 1);
+
+        neighbors.insert("me", Viewer::new("me", 3, 3, 3, 3, 3));
+
+        // A lot of viewers have the same preferences, so there are many equal distances
+        for (index, name) in names.iter().enumerate() {
+            let index = index as i32;
+            let viewer = Viewer::new(
+                name,
+                index % 5,
+                index * 3 % 5,
+                index * 7 % 4,
+                index / 100,
+                index % 3,
+            );
+
+            neighbors.insert(name, viewer);
+        }
+
+        for neighbors_count in [0, 1, 10, 137, 499, 600] {
+            // when
+            let sequential = k_nearest_neighbor(&neighbors, "me", neighbors_count);
+            let parallel = k_nearest_neighbor_parallel(&neighbors, "me", neighbors_count);
+
+            // then
+            assert_eq!(neighbors_count.min(500), parallel.len());
+            assert_eq!(sequential, parallel);
+        }
+    }
 }
@@ -0,0 +1,148 @@
+use crate::algorithms::dijkstra_search::dijkstra_search_filtered;
+use crate::weighted_graph::WeightedGraph;
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// # Description
+/// Yen's algorithm finds up to `k` shortest loopless paths(paths which don't visit the same node twice) from `start` to `finish`.
+/// Paths are returned in increasing cost order, if there are fewer than `k` paths, then all of them are returned.
+///
+/// Realisation details:
+/// 1. The first path is just the shortest path found by Dijkstra search.
+/// 2. Every next path deviates from one of the already found paths at some node, which is called "spur node".
+///    So for every node of the previous path(except the finish) we:
+///     - take the part of the path before the spur node as a "root path"
+///     - forbid edges which continue the root path the same way as already found paths do, so we can't find the same path again
+///     - forbid nodes of the root path, so the new path can't go back and make a loop
+///     - run Dijkstra search from the spur node to the finish, root path + found spur path is a candidate
+/// 3. The cheapest candidate becomes the next path. Repeat 2nd step till we have `k` paths or there are no candidates left.
+///
+/// **Note** that edges are forbidden by their endpoints, so parallel edges between two nodes are treated as one(the cheapest) edge.
+///
+/// # Complexity
+/// `O(k * n * D)`, where `n` is a number of nodes in a path and `D` is complexity of Dijkstra search.
+pub fn k_shortest_paths<K>(graph: &WeightedGraph<K>, start: K, finish: K, k: usize) -> Vec<Vec<K>>
+where
+    K: Ord + Hash + Clone + Eq,
+{
+    if k == 0 {
+        return vec![];
+    }
+
+    let Some(shortest_path) = dijkstra_search_filtered(graph, &start, &finish, |_, _| true) else {
+        return vec![];
+    };
+    let mut paths = vec![shortest_path];
+    let mut candidates: Vec<(i32, Vec<K>)> = vec![];
+
+    while paths.len() < k {
+        let previous_path = &paths[paths.len() - 1];
+
+        for spur_index in 0..previous_path.len() - 1 {
+            let spur_node = &previous_path[spur_index];
+            let root_path = &previous_path[..spur_index];
+
+            let forbidden_edges = paths
+                .iter()
+                .filter(|path| {
+                    path.len() > spur_index + 1
+                        && path[..=spur_index] == previous_path[..=spur_index]
+                })
+                .map(|path| (path[spur_index].clone(), path[spur_index + 1].clone()))
+                .collect::<HashSet<_>>();
+            let forbidden_nodes = root_path.iter().collect::<HashSet<_>>();
+
+            let spur_path = dijkstra_search_filtered(graph, spur_node, &finish, |from, to| {
+                !forbidden_nodes.contains(from)
+                    && !forbidden_nodes.contains(to)
+                    && !forbidden_edges.contains(&(from.clone(), to.clone()))
+            });
+
+            let Some(spur_path) = spur_path else {
+                continue;
+            };
+            let candidate = root_path
+                .iter()
+                .cloned()
+                .chain(spur_path)
+                .collect::<Vec<_>>();
+
+            if paths.contains(&candidate) || candidates.iter().any(|(_, path)| path == &candidate) {
+                continue;
+            }
+
+            if let Some(cost) = graph.path_weight(&candidate) {
+                candidates.push((cost, candidate));
+            }
+        }
+
+        let Some(cheapest) = candidates
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, (cost, _))| *cost)
+            .map(|(index, _)| index)
+        else {
+            break;
+        };
+
+        paths.push(candidates.remove(cheapest).1);
+    }
+
+    paths
+}
+
+#[cfg(test)]
+mod tests {
+    use super::k_shortest_paths;
+    use crate::weighted_graph::WeightedGraph;
+
+    fn get_graph() -> WeightedGraph<&'static str> {
+        let mut graph = WeightedGraph::new();
+
+        for id in ["start", "a", "b", "c", "finish"] {
+            graph.insert(id);
+        }
+
+        graph.connect("start", "a", 1);
+        graph.connect("a", "finish", 1);
+        graph.connect("start", "b", 2);
+        graph.connect("b", "finish", 2);
+        graph.connect("a", "c", 3);
+        graph.connect("c", "finish", 3);
+
+        graph
+    }
+
+    #[test]
+    fn should_return_paths_in_cost_order() {
+        // when
+        let paths = k_shortest_paths(&get_graph(), "start", "finish", 2);
+
+        // then
+        assert_eq!(
+            vec![vec!["start", "a", "finish"], vec!["start", "b", "finish"]],
+            paths
+        );
+    }
+
+    #[test]
+    fn should_return_all_paths_if_there_are_fewer_than_k() {
+        // when
+        let paths = k_shortest_paths(&get_graph(), "start", "finish", 10);
+
+        // then
+        assert_eq!(
+            vec![
+                vec!["start", "a", "finish"],
+                vec!["start", "b", "finish"],
+                vec!["start", "a", "c", "finish"]
+            ],
+            paths
+        );
+    }
+
+    #[test]
+    fn should_return_nothing_if_finish_is_not_reachable() {
+        assert!(k_shortest_paths(&get_graph(), "finish", "start", 3).is_empty());
+    }
+}
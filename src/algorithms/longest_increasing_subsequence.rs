@@ -0,0 +1,76 @@
+use crate::algorithms::partition_point;
+
+/// # Description
+/// Finds the longest strictly increasing subsequence of a slice(elements don't have to be adjacent, but have to keep their order).
+/// If there are several subsequences of the same length, then one of them is returned.
+///
+/// This is a "patience sorting" approach. We keep `tails`, where `tails[i]` is an index of the smallest element
+/// which ends an increasing subsequence of length `i + 1`. `tails` are always sorted by their elements, so:
+/// 1. For every element we search with binary search(`partition_point`) for the first tail which is not lower than the element.
+/// 2. If there is no such tail, then the element extends the longest subsequence, so we push it to `tails`.
+///    Otherwise the element replaces the found tail, as it ends a subsequence of the same length, but with a lower element, which is better for next elements.
+/// 3. For every element we also remember a previous element of its subsequence(the tail before it), so in the end
+///    we can go back from the last tail and build the subsequence itself.
+///
+/// # Complexity
+/// `O(n log n)`, `n` binary searches over `tails`, which are never longer than `n`.
+pub fn longest_increasing_subsequence<T>(slice: &[T]) -> Vec<T>
+where
+    T: Ord + Copy,
+{
+    let mut tails: Vec<usize> = vec![];
+    let mut previous: Vec<Option<usize>> = vec![None; slice.len()];
+
+    for (index, item) in slice.iter().enumerate() {
+        let position = partition_point(tails.len(), |tail| &slice[tails[tail]] >= item);
+
+        if position > 0 {
+            previous[index] = Some(tails[position - 1]);
+        }
+
+        if position == tails.len() {
+            tails.push(index);
+        } else {
+            tails[position] = index;
+        }
+    }
+
+    let mut subsequence = Vec::with_capacity(tails.len());
+    let mut next = tails.last().copied();
+
+    while let Some(index) = next {
+        subsequence.push(slice[index]);
+        next = previous[index];
+    }
+
+    subsequence.reverse();
+    subsequence
+}
+
+#[cfg(test)]
+mod tests {
+    use super::longest_increasing_subsequence;
+
+    fn is_increasing(list: &[i32]) -> bool {
+        list.windows(2).all(|pair| pair[0] < pair[1])
+    }
+
+    #[test]
+    fn should_find_longest_increasing_subsequence() {
+        let subsequence = longest_increasing_subsequence(&[10, 9, 2, 5, 3, 7, 101, 18]);
+
+        assert_eq!(4, subsequence.len());
+        assert!(is_increasing(&subsequence));
+        assert_eq!(vec![2, 3, 7, 18], subsequence);
+    }
+
+    #[test]
+    fn should_not_take_equal_elements() {
+        assert_eq!(vec![7], longest_increasing_subsequence(&[7, 7, 7]));
+    }
+
+    #[test]
+    fn should_return_empty_subsequence_for_empty_slice() {
+        assert!(longest_increasing_subsequence::<i32>(&[]).is_empty());
+    }
+}
@@ -0,0 +1,62 @@
+/// # Description
+/// Boyer-Moore voting algorithm finds an element which appears more than `n / 2` times in a list.
+///
+/// Realisation details:
+/// 1. We keep a `candidate` and a `count`. When `count` is `0`, the current element becomes a new `candidate`.
+/// 2. If the current element is the `candidate`, then we increase `count`, otherwise we decrease it.
+///    We can think of it as every other element "cancelling" one vote of the `candidate`.
+/// 3. If there is a majority element, then it can't be cancelled completely, as there are more of it than all other elements together,
+///    so it's going to be the `candidate` in the end.
+/// 4. But if there is no majority element, then the `candidate` is just some element, so we need a second pass to count it and verify.
+///
+/// # Complexity
+/// `O(n)` time and `O(1)` additional space.
+pub fn majority_element<T>(slice: &[T]) -> Option<T>
+where
+    T: Eq + Copy,
+{
+    let mut candidate = *slice.first()?;
+    let mut count = 0;
+
+    for &item in slice {
+        if count == 0 {
+            candidate = item;
+        }
+
+        if item == candidate {
+            count += 1;
+        } else {
+            count -= 1;
+        }
+    }
+
+    let occurrences = slice.iter().filter(|&&item| item == candidate).count();
+
+    if occurrences > slice.len() / 2 {
+        Some(candidate)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::majority_element;
+
+    #[test]
+    fn should_find_majority() {
+        assert_eq!(Some(2), majority_element(&[2, 2, 1, 1, 1, 2, 2]));
+        assert_eq!(Some('a'), majority_element(&['a']));
+    }
+
+    #[test]
+    fn should_return_none_if_there_is_no_majority() {
+        assert_eq!(None, majority_element(&[1, 2, 1, 2]));
+        assert_eq!(None, majority_element(&[1, 2, 3, 3]));
+    }
+
+    #[test]
+    fn should_return_none_for_empty_slice() {
+        assert_eq!(None, majority_element::<i32>(&[]));
+    }
+}
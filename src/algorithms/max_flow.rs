@@ -0,0 +1,164 @@
+use crate::algorithms::dijkstra_search::build_chain;
+use crate::weighted_graph::WeightedGraph;
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+
+/// Finds a path with some free capacity left from `source` to `sink` via BFS, so it's always one of the shortest(by a number of edges) paths.
+fn find_augmenting_path<K>(
+    neighbours: &HashMap<K, Vec<K>>,
+    residual: &HashMap<(K, K), i32>,
+    source: &K,
+    sink: &K,
+) -> Option<Vec<K>>
+where
+    K: Hash + Clone + Eq,
+{
+    let mut parents = HashMap::new();
+    let mut deque = VecDeque::from([source.clone()]);
+
+    while let Some(node) = deque.pop_front() {
+        if &node == sink {
+            return Some(build_chain(node, &parents));
+        }
+
+        for child in neighbours.get(&node).into_iter().flatten() {
+            let has_capacity = residual[&(node.clone(), child.clone())] > 0;
+
+            if has_capacity && child != source && !parents.contains_key(child) {
+                parents.insert(child.clone(), node.clone());
+                deque.push_back(child.clone());
+            }
+        }
+    }
+
+    None
+}
+
+/// # Description
+/// Finds the maximum flow from `source` to `sink`, edge weights are capacities(how much can flow through an edge).
+/// It's Edmonds-Karp algorithm, i.e. Ford-Fulkerson method where augmenting paths are found via BFS.
+///
+/// Realisation details:
+/// 1. Build a residual graph: every edge `A -> B` gets its capacity, and a reversed edge `B -> A` gets `0` capacity.
+///    Parallel edges are merged into one edge with summed capacity.
+/// 2. Find the shortest path from `source` to `sink` where every edge has some capacity left(an augmenting path).
+/// 3. The lowest capacity on the path is how much more we can push through it. We subtract it from every edge on the path
+///    and add it to every reversed edge, so later paths can "cancel" the flow if there is a better way to route it.
+/// 4. Repeat 2-3 steps till there is no augmenting path. A sum of all pushed flows is the maximum flow.
+///
+/// Edges with `0` or negative weight can't carry any flow. Returns `0` if `source` or `sink` does not exist, or if they are the same node.
+///
+/// # Complexity
+/// `O(n * e^2)`, where `n` is a number of nodes and `e` is a number of edges.
+pub fn max_flow<K>(graph: &WeightedGraph<K>, source: K, sink: K) -> i32
+where
+    K: Ord + Hash + Clone + Eq,
+{
+    if graph.get(&source).is_none() || graph.get(&sink).is_none() || source == sink {
+        return 0;
+    }
+
+    let mut neighbours: HashMap<K, Vec<K>> = HashMap::new();
+    let mut residual: HashMap<(K, K), i32> = HashMap::new();
+
+    for node in graph.nodes() {
+        for edge in node.nodes().iter() {
+            let (from, to) = (node.id().clone(), edge.node().id().clone());
+
+            // Both directions are added only once, even if there are parallel or reversed edges
+            if !residual.contains_key(&(from.clone(), to.clone())) {
+                neighbours.entry(from.clone()).or_default().push(to.clone());
+                neighbours.entry(to.clone()).or_default().push(from.clone());
+                residual.insert((to.clone(), from.clone()), 0);
+                residual.insert((from.clone(), to.clone()), 0);
+            }
+
+            *residual.get_mut(&(from, to)).unwrap() += edge.weight().max(0);
+        }
+    }
+
+    let mut flow = 0;
+
+    while let Some(path) = find_augmenting_path(&neighbours, &residual, &source, &sink) {
+        let bottleneck = path
+            .windows(2)
+            .map(|pair| residual[&(pair[0].clone(), pair[1].clone())])
+            .min()
+            .unwrap();
+
+        for pair in path.windows(2) {
+            *residual
+                .get_mut(&(pair[0].clone(), pair[1].clone()))
+                .unwrap() -= bottleneck;
+            *residual
+                .get_mut(&(pair[1].clone(), pair[0].clone()))
+                .unwrap() += bottleneck;
+        }
+
+        flow += bottleneck;
+    }
+
+    flow
+}
+
+#[cfg(test)]
+mod tests {
+    use super::max_flow;
+    use crate::weighted_graph::WeightedGraph;
+
+    #[test]
+    fn should_find_max_flow() {
+        // given
+        let mut graph = WeightedGraph::new();
+
+        for id in ["s", "a", "b", "c", "d", "t"] {
+            graph.insert(id);
+        }
+
+        graph.connect("s", "a", 16);
+        graph.connect("s", "c", 13);
+        graph.connect("a", "b", 12);
+        graph.connect("c", "a", 4);
+        graph.connect("b", "c", 9);
+        graph.connect("c", "d", 14);
+        graph.connect("d", "b", 7);
+        graph.connect("b", "t", 20);
+        graph.connect("d", "t", 4);
+
+        // when
+        let flow = max_flow(&graph, "s", "t");
+
+        // then
+        assert_eq!(23, flow);
+    }
+
+    #[test]
+    fn should_not_count_cross_edge_twice() {
+        // Two disjoint paths 1 -> 2 -> 4 and 1 -> 3 -> 4, the cross edge 2 -> 3 doesn't add any flow
+        let mut graph = WeightedGraph::new();
+
+        for id in 1..=4 {
+            graph.insert(id);
+        }
+
+        graph.connect(1, 2, 1);
+        graph.connect(1, 3, 1);
+        graph.connect(2, 3, 1);
+        graph.connect(2, 4, 1);
+        graph.connect(3, 4, 1);
+
+        assert_eq!(2, max_flow(&graph, 1, 4));
+    }
+
+    #[test]
+    fn should_return_zero_if_sink_is_unreachable() {
+        let mut graph = WeightedGraph::new();
+
+        graph.insert(1);
+        graph.insert(2);
+        graph.connect(2, 1, 5);
+
+        assert_eq!(0, max_flow(&graph, 1, 2));
+        assert_eq!(0, max_flow(&graph, 1, 3));
+    }
+}
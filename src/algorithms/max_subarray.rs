@@ -0,0 +1,72 @@
+/// # Description
+/// Kadane's algorithm finds a contiguous subarray with the largest sum.
+/// Returns `(start, end, sum)`, where `start` and `end` are inclusive indexes of the subarray, or `None` for an empty slice.
+///
+/// Realisation details:
+/// 1. We go through the slice once and keep the best sum of a subarray which ends at the current element.
+/// 2. For every element we decide whether it's better to extend the previous subarray or to start a new one from the current element.
+///    If the previous best sum is negative, then it can only make things worse, so we start a new subarray.
+/// 3. We also keep the best subarray seen so far, which is the answer in the end.
+///
+/// It's a classic example of dynamic programming, the answer for the current element is built from the answer for the previous one.
+/// As a subarray can't be empty, for an all-negative slice the answer is the biggest(the least negative) element.
+///
+/// Sums are kept in `i64`, so even a sum of a very long slice of `i32::MAX` items doesn't overflow, e.g. `[i32::MAX, 1]` sums to `i32::MAX + 1`.
+///
+/// # Complexity
+/// `O(n)`
+pub fn max_subarray(slice: &[i32]) -> Option<(usize, usize, i64)> {
+    let mut best = (0, 0, i64::from(*slice.first()?));
+    let mut current_start = 0;
+    let mut current_sum = best.2;
+
+    for (index, &item) in slice.iter().enumerate().skip(1) {
+        if current_sum < 0 {
+            current_start = index;
+            current_sum = i64::from(item);
+        } else {
+            current_sum += i64::from(item);
+        }
+
+        if current_sum > best.2 {
+            best = (current_start, index, current_sum);
+        }
+    }
+
+    Some(best)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::max_subarray;
+
+    #[test]
+    fn should_find_max_subarray() {
+        assert_eq!(
+            Some((3, 6, 6)),
+            max_subarray(&[-2, 1, -3, 4, -1, 2, 1, -5, 4])
+        );
+        assert_eq!(Some((0, 2, 6)), max_subarray(&[1, 2, 3]));
+    }
+
+    #[test]
+    fn should_find_biggest_element_if_all_are_negative() {
+        assert_eq!(Some((2, 2, -1)), max_subarray(&[-3, -2, -1, -4]));
+    }
+
+    #[test]
+    fn should_sum_large_values_without_overflow() {
+        let max = i64::from(i32::MAX);
+
+        assert_eq!(Some((0, 1, max + 1)), max_subarray(&[i32::MAX, 1]));
+        assert_eq!(
+            Some((1, 3, max * 3)),
+            max_subarray(&[-5, i32::MAX, i32::MAX, i32::MAX, i32::MIN])
+        );
+    }
+
+    #[test]
+    fn should_return_none_for_empty_slice() {
+        assert_eq!(None, max_subarray(&[]));
+    }
+}
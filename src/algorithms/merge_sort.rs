@@ -1,19 +1,102 @@
+use crate::algorithms::insertion_sort::insertion_sort_by;
+use crate::algorithms::partition_point;
+use std::cmp::Ordering;
+
+/// Parts of a slice which are not longer than this are sorted by `insertion_sort_by` in `merge_sort`.
+pub const MERGE_SORT_CUTOFF: usize = 16;
+
 // TODO: Description
-#[allow(dead_code)]
 pub fn merge_sort<T>(arr: &mut [T])
 where
     T: PartialOrd + Copy,
 {
+    merge_sort_with_cutoff(arr, MERGE_SORT_CUTOFF);
+}
+
+/// # Description
+/// The same merge sort as `merge_sort`, but parts which are not longer than `cutoff` are sorted by `insertion_sort_by` instead of splitting them further.
+/// Insertion sort is `O(n^2)`, but on small parts it's faster than merging, as it doesn't copy halves and has a smaller constant.
+/// `merge_sort` uses `MERGE_SORT_CUTOFF`, `cutoff` lower than `2` means a plain merge sort without insertion sort at all.
+///
+/// Both sorts are stable and use the same `compare_partial` rule, so the result is the same for any `cutoff`, even with `NaN`s(they go to the end).
+///
+/// # Complexity
+/// `O(n log n)` time and `O(n)` additional memory for halves, insertion sort adds `O(n * cutoff)` at most.
+pub fn merge_sort_with_cutoff<T>(arr: &mut [T], cutoff: usize)
+where
+    T: PartialOrd + Copy,
+{
+    if arr.len() <= cutoff {
+        insertion_sort_by(arr, compare_partial);
+        return;
+    }
+
     if arr.len() <= 1 {
         return;
     }
 
-    let mut first_half = arr.iter().copied().take(arr.len() / 2).collect::<Vec<_>>();
-    let mut second_half = arr.iter().copied().skip(arr.len() / 2).collect::<Vec<_>>();
+    let mut first_half = arr[..arr.len() / 2].to_vec();
+    let mut second_half = arr[arr.len() / 2..].to_vec();
+
+    merge_sort_with_cutoff(&mut first_half, cutoff);
+    merge_sort_with_cutoff(&mut second_half, cutoff);
+
+    merge_halves(arr, &first_half, &second_half, &compare_partial);
+}
+
+/// Compares elements which may be incomparable, e.g. `NaN`. If we just treated them as equal to everything, then the order wouldn't be transitive
+/// (`1 == NaN == 0`, but `1 > 0`), and different sorts(or the same sort with a different `cutoff`) would put them to different places.
+/// Instead, an element which is incomparable even with itself goes after all other elements, and such elements are equal to each other,
+/// so `NaN`s end up at the end in their original order.
+fn compare_partial<T: PartialOrd>(first: &T, second: &T) -> Ordering {
+    first.partial_cmp(second).unwrap_or_else(|| {
+        let is_incomparable = |item: &T| item.partial_cmp(item).is_none();
+
+        is_incomparable(first).cmp(&is_incomparable(second))
+    })
+}
+
+/// # Description
+/// The same merge sort as `merge_sort`, but elements are compared via `cmp`, so any ordering can be used,
+/// e.g. `|a, b| b.cmp(a)` for descending order or `|a, b| a.year.cmp(&b.year)` to sort structs by a field.
+///
+/// The sort is stable: if `cmp` returns `Ordering::Equal`, then elements keep their relative order,
+/// as on equality we always take an element from the first half.
+///
+/// # Complexity
+/// `O(n log n)` time and `O(n)` additional memory for halves.
+pub fn merge_sort_by<T, F>(arr: &mut [T], cmp: F)
+where
+    T: Clone,
+    F: Fn(&T, &T) -> Ordering,
+{
+    fn sort<T, F>(arr: &mut [T], cmp: &F)
+    where
+        T: Clone,
+        F: Fn(&T, &T) -> Ordering,
+    {
+        if arr.len() <= 1 {
+            return;
+        }
+
+        let mut first_half = arr[..arr.len() / 2].to_vec();
+        let mut second_half = arr[arr.len() / 2..].to_vec();
 
-    merge_sort(&mut first_half);
-    merge_sort(&mut second_half);
+        sort(&mut first_half, cmp);
+        sort(&mut second_half, cmp);
 
+        merge_halves(arr, &first_half, &second_half, cmp);
+    }
+
+    sort(arr, &cmp);
+}
+
+/// Merges sorted `first_half` and `second_half` into `arr`. On equality an element from the first half goes first, so the merge is stable.
+fn merge_halves<T, F>(arr: &mut [T], first_half: &[T], second_half: &[T], cmp: &F)
+where
+    T: Clone,
+    F: Fn(&T, &T) -> Ordering,
+{
     let mut first_half_index = 0;
     let mut second_half_index = 0;
 
@@ -25,20 +108,20 @@ where
             second_half.get(second_half_index),
         ) {
             (Some(first), Some(second)) => {
-                if first < second {
-                    arr[insertion_index] = *first;
-                    first_half_index += 1;
-                } else {
-                    arr[insertion_index] = *second;
+                if cmp(first, second) == Ordering::Greater {
+                    arr[insertion_index] = second.clone();
                     second_half_index += 1;
+                } else {
+                    arr[insertion_index] = first.clone();
+                    first_half_index += 1;
                 }
             }
             (Some(first), None) => {
-                arr[insertion_index] = *first;
+                arr[insertion_index] = first.clone();
                 first_half_index += 1;
             }
             (None, Some(second)) => {
-                arr[insertion_index] = *second;
+                arr[insertion_index] = second.clone();
                 second_half_index += 1;
             }
             _ => break,
@@ -46,9 +129,132 @@ where
     }
 }
 
+/// # Description
+/// Merges two adjacent sorted parts of a slice(`slice[..mid]` and `slice[mid..]`) into one sorted slice without an additional buffer.
+/// The merge is stable, i.e. equal elements keep their relative order.
+///
+/// Realisation details(it's a rotation based merge):
+/// 1. Take a middle element of the longer part as a pivot.
+/// 2. Find with binary search a place of the pivot in the other part, i.e. elements of the other part which have to go before(or after) the pivot.
+/// 3. Rotate the slice between the pivot and that place, so that both parts are split in two: everything on the left from the pivot is lower, everything on the right is bigger.
+/// 4. Now we have two independent smaller merges on both sides of the pivot, so we repeat 1-3 steps for them.
+///
+/// # Complexity
+/// The usual merge(as in `merge_sort`) is `O(n)` time, but it needs `O(n)` additional memory for halves.
+/// This merge needs only `O(log n)` memory for recursion, but it takes `O(n log n)` time because of rotations,
+/// which makes a merge sort built on top of it `O(n log^2 n)`. So it's a trade: less memory for more time.
+///
+/// # Panics
+/// Panics if `mid` is bigger than the length of the slice.
+pub fn merge_in_place<T>(slice: &mut [T], mid: usize)
+where
+    T: Ord,
+{
+    assert!(mid <= slice.len(), "\"mid\" is out of the slice bounds");
+
+    let first_half_len = mid;
+    let second_half_len = slice.len() - mid;
+
+    if first_half_len == 0 || second_half_len == 0 {
+        return;
+    }
+
+    if first_half_len + second_half_len == 2 {
+        if slice[1] < slice[0] {
+            slice.swap(0, 1);
+        }
+
+        return;
+    }
+
+    let (first_cut, second_cut) = if first_half_len >= second_half_len {
+        let first_cut = first_half_len / 2;
+        // Elements of the second half which are lower than the pivot go before it, equal ones stay after it to keep stability
+        let second_cut =
+            mid + partition_point(second_half_len, |i| slice[mid + i] >= slice[first_cut]);
+
+        (first_cut, second_cut)
+    } else {
+        let second_cut = mid + second_half_len / 2;
+        // Elements of the first half which are bigger than the pivot go after it, equal ones stay before it to keep stability
+        let first_cut = partition_point(first_half_len, |i| slice[i] > slice[second_cut]);
+
+        (first_cut, second_cut)
+    };
+
+    slice[first_cut..second_cut].rotate_left(mid - first_cut);
+
+    let new_mid = first_cut + (second_cut - mid);
+
+    merge_in_place(&mut slice[..new_mid], first_cut);
+    merge_in_place(&mut slice[new_mid..], second_cut - new_mid);
+}
+
 #[cfg(test)]
 mod tests {
-    use super::merge_sort;
+    use super::{merge_in_place, merge_sort, merge_sort_by, merge_sort_with_cutoff};
+    use crate::algorithms::get_random_list;
+    use std::cmp::Ordering;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Book {
+        pages: u32,
+        release_year: u16,
+    }
+
+    fn get_struct_list() -> Vec<Book> {
+        [
+            (12, 1952),
+            (51515, 2015),
+            (25, 1985),
+            (14, 1994),
+            (222, 1800),
+            (7, 1985),
+        ]
+        .into_iter()
+        .map(|(pages, release_year)| Book {
+            pages,
+            release_year,
+        })
+        .collect()
+    }
+
+    #[test]
+    fn should_sort_array_desc() {
+        let mut array: [i32; 8] = [3, 41, 52, 26, 38, 57, 9, 49];
+
+        merge_sort_by(&mut array, |a, b| b.cmp(a));
+
+        assert_eq!(array, [57, 52, 49, 41, 38, 26, 9, 3]);
+    }
+
+    #[test]
+    fn should_sort_struct_list_by_year() {
+        // given
+        let mut list = get_struct_list();
+
+        // when
+        merge_sort_by(&mut list, |a, b| a.release_year.cmp(&b.release_year));
+
+        // then
+        let sorted: Vec<_> = list
+            .iter()
+            .map(|book| (book.pages, book.release_year))
+            .collect();
+
+        // Both books from 1985 keep their original order, as the sort is stable
+        assert_eq!(
+            vec![
+                (222, 1800),
+                (12, 1952),
+                (25, 1985),
+                (7, 1985),
+                (14, 1994),
+                (51515, 2015)
+            ],
+            sorted
+        );
+    }
 
     #[test]
     fn should_sort_array() {
@@ -58,4 +264,104 @@ mod tests {
 
         assert_eq!(array, [3, 9, 26, 38, 41, 49, 52, 57]);
     }
+
+    #[test]
+    fn should_sort_the_same_with_any_cutoff() {
+        // given
+        let fixtures = [
+            vec![3, 41, 52, 26, 38, 57, 9, 49],
+            (0..40).rev().collect(),
+            vec![],
+            vec![1],
+            get_random_list(5000, 11, 1000),
+        ];
+
+        for fixture in fixtures {
+            let mut expected = fixture.clone();
+
+            merge_sort_with_cutoff(&mut expected, 0);
+
+            for cutoff in [1, 2, 7, 16, 64, 10_000] {
+                // when
+                let mut list = fixture.clone();
+
+                merge_sort_with_cutoff(&mut list, cutoff);
+
+                // then
+                assert_eq!(expected, list);
+            }
+
+            let mut list = fixture.clone();
+
+            merge_sort(&mut list);
+
+            assert_eq!(expected, list);
+            assert!(expected.windows(2).all(|pair| pair[0] <= pair[1]));
+        }
+    }
+
+    #[test]
+    fn should_put_nan_to_the_end_with_any_cutoff() {
+        for cutoff in [0, 2, 16] {
+            // given
+            let mut list = [2.0, f64::NAN, 1.0, 0.0, f64::NAN, -1.0];
+
+            // when
+            merge_sort_with_cutoff(&mut list, cutoff);
+
+            // then
+            assert_eq!([-1.0, 0.0, 1.0, 2.0], list[..4]);
+            assert!(list[4..].iter().all(|item| item.is_nan()));
+        }
+    }
+
+    #[test]
+    fn should_keep_order_of_equal_elements_with_cutoff() {
+        // Compared only by the first field, the second one is an original index
+        #[derive(Clone, Copy)]
+        struct Key(usize, usize);
+
+        impl PartialEq for Key {
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+
+        impl PartialOrd for Key {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                self.0.partial_cmp(&other.0)
+            }
+        }
+
+        let mut list: Vec<Key> = (0..100).map(|index| Key(index % 3, index)).collect();
+
+        merge_sort(&mut list);
+
+        assert!(list
+            .windows(2)
+            .all(|pair| pair[0].0 < pair[1].0 || pair[0].1 < pair[1].1));
+    }
+
+    #[test]
+    fn should_merge_halves_in_place() {
+        let mut array: [i32; 10] = [1, 4, 9, 12, 20, 2, 3, 4, 10, 25];
+
+        merge_in_place(&mut array, 5);
+
+        assert_eq!(array, [1, 2, 3, 4, 4, 9, 10, 12, 20, 25]);
+    }
+    #[test]
+    fn should_merge_halves_of_different_length_in_place() {
+        let mut array: [i32; 8] = [5, 0, 1, 2, 3, 4, 6, 7];
+
+        merge_in_place(&mut array, 1);
+
+        assert_eq!(array, [0, 1, 2, 3, 4, 5, 6, 7]);
+
+        let mut array: [i32; 5] = [3, 6, 7, 8, 1];
+
+        merge_in_place(&mut array, 4);
+
+        assert_eq!(array, [1, 3, 6, 7, 8]);
+    }
 }
@@ -0,0 +1,185 @@
+/// # Description
+/// Finds the greatest common divisor of `a` and `b` with Euclid's algorithm: a common divisor of `a` and `b` also divides `a % b`,
+/// so we replace `(a, b)` with `(b, a % b)` till `b` becomes `0`, then `a` is the answer.
+///
+/// `gcd(0, n)` is `n`, as every number divides `0`, and `gcd(0, 0)` is `0`.
+///
+/// # Complexity
+/// `O(log min(a, b))`, the smaller number at least halves every two steps.
+#[must_use]
+pub fn gcd(a: u64, b: u64) -> u64 {
+    let mut a = a;
+    let mut b = b;
+
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+/// # Description
+/// Finds the least common multiple of `a` and `b`, it's `a * b / gcd(a, b)`.
+/// We divide before multiplying, so it overflows only if the result itself doesn't fit into `u64`.
+///
+/// `lcm(0, n)` is `0`.
+///
+/// # Complexity
+/// `O(log min(a, b))`, the same as `gcd`.
+///
+/// # Panics
+///
+/// Panics if the result doesn't fit into `u64`.
+#[must_use]
+pub fn lcm(a: u64, b: u64) -> u64 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+
+    (a / gcd(a, b))
+        .checked_mul(b)
+        .expect("Least common multiple doesn't fit into u64")
+}
+
+/// # Description
+/// Raises `base` to the power of `exp` with exponentiation by squaring(binary exponentiation).
+/// Instead of `exp` multiplications we go through bits of `exp`: `base` is squared on every bit(`base`, `base^2`, `base^4`...),
+/// and it's multiplied into the result only for set bits, e.g. `3^13 = 3^8 * 3^4 * 3^1`, as `13` is `0b1101`.
+///
+/// `pow(base, 0)` is `1`, including `pow(0, 0)`.
+///
+/// # Complexity
+/// `O(log exp)` multiplications.
+///
+/// # Panics
+///
+/// Panics if the result doesn't fit into `u64`.
+#[must_use]
+pub fn pow(base: u64, exp: u64) -> u64 {
+    let mut result: u64 = 1;
+    let mut base = base;
+    let mut exp = exp;
+
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result
+                .checked_mul(base)
+                .expect("Power doesn't fit into u64");
+        }
+
+        exp >>= 1;
+
+        // The last square is not needed, and it may overflow even if the result fits
+        if exp > 0 {
+            base = base.checked_mul(base).expect("Power doesn't fit into u64");
+        }
+    }
+
+    result
+}
+
+/// # Description
+/// Calculates `base^exp % modulus` the same way as `pow`, but every product is taken by `modulus`, so numbers never grow above it.
+/// Products are calculated in `u128`, so any `u64` modulus works without overflow.
+///
+/// `exp == 0` gives `1`, unless `modulus` is `1`, as everything is `0` by modulus `1`.
+///
+/// # Complexity
+/// `O(log exp)` multiplications.
+///
+/// # Panics
+///
+/// Panics if `modulus` is `0`.
+#[must_use]
+pub fn pow_mod(base: u64, exp: u64, modulus: u64) -> u64 {
+    assert!(modulus != 0, "Modulus can't be 0");
+
+    let modulus = u128::from(modulus);
+    let mut result = 1 % modulus;
+    let mut base = u128::from(base) % modulus;
+    let mut exp = exp;
+
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % modulus;
+        }
+
+        base = base * base % modulus;
+        exp >>= 1;
+    }
+
+    // The result is lower than `modulus`, which came from `u64`
+    u64::try_from(result).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{gcd, lcm, pow, pow_mod};
+
+    fn naive_pow_mod(base: u64, exp: u64, modulus: u64) -> u64 {
+        (0..exp).fold(1 % modulus, |result, _| result * base % modulus)
+    }
+
+    #[test]
+    fn should_match_naive_power() {
+        for base in 0..10 {
+            for exp in 0..10 {
+                assert_eq!((0..exp).fold(1, |result, _| result * base), pow(base, exp));
+
+                for modulus in 1..20 {
+                    assert_eq!(
+                        naive_pow_mod(base, exp, modulus),
+                        pow_mod(base, exp, modulus)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn should_handle_edge_cases_of_power() {
+        assert_eq!(1, pow(0, 0));
+        assert_eq!(1 << 63, pow(2, 63));
+        assert_eq!(0, pow_mod(5, 0, 1));
+        assert_eq!(1, pow_mod(5, 0, 7));
+        // Fermat's little theorem: a^(p-1) = 1 mod p for a prime p
+        assert_eq!(1, pow_mod(123_456_789, 1_000_000_006, 1_000_000_007));
+        assert_eq!(u64::MAX - 1, pow_mod(u64::MAX - 1, 1, u64::MAX));
+    }
+
+    #[test]
+    #[should_panic(expected = "doesn't fit into u64")]
+    fn should_panic_if_power_overflows() {
+        let _ = pow(2, 64);
+    }
+
+    #[test]
+    fn should_handle_coprime_numbers() {
+        assert_eq!(1, gcd(17, 4));
+        assert_eq!(1, gcd(4, 17));
+        assert_eq!(68, lcm(17, 4));
+    }
+
+    #[test]
+    fn should_find_shared_factor() {
+        assert_eq!(6, gcd(48, 18));
+        assert_eq!(144, lcm(48, 18));
+        assert_eq!(7, gcd(7, 7));
+        assert_eq!(7, lcm(7, 7));
+        assert_eq!(u64::MAX, lcm(u64::MAX, u64::MAX));
+    }
+
+    #[test]
+    fn should_handle_zero() {
+        assert_eq!(5, gcd(0, 5));
+        assert_eq!(5, gcd(5, 0));
+        assert_eq!(0, gcd(0, 0));
+        assert_eq!(0, lcm(0, 5));
+    }
+
+    #[test]
+    #[should_panic(expected = "doesn't fit into u64")]
+    fn should_panic_on_overflow() {
+        let _ = lcm(u64::MAX, u64::MAX - 1);
+    }
+}
@@ -1,40 +1,88 @@
+use std::cmp::Ordering;
+use std::ops::Range;
+
 pub fn quick_sort(slice: &mut [i32]) {
-    if slice.len() < 2 {
-        return;
+    quick_sort_by(slice, i32::cmp);
+}
+
+/// # Description
+/// The same quick sort as `quick_sort`, but elements are compared via `cmp`, so any ordering can be used,
+/// e.g. `|a, b| b.cmp(a)` for descending order or a custom rule for structs. Unlike `merge_sort_by` it's not stable.
+///
+/// # Complexity
+/// `O(n log n)` on average, `O(n^2)` in the worst case, the median of three pivot makes the worst case unlikely.
+pub fn quick_sort_by<T, F>(slice: &mut [T], cmp: F)
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    fn sort<T, F>(slice: &mut [T], cmp: &F)
+    where
+        F: Fn(&T, &T) -> Ordering,
+    {
+        if slice.len() < 2 {
+            return;
+        }
+
+        let pivot_index = partitioning(slice, cmp);
+
+        // We can skip pivot elements as we know that elements on the left from it are less than pivot and elements on the right are bigger
+        sort(&mut slice[..pivot_index], cmp);
+        sort(&mut slice[pivot_index + 1..], cmp);
     }
 
-    let pivot_index = partitioning(slice);
+    sort(slice, &cmp);
+}
 
-    // We can skip pivot elements as we know that elements on the left from it are less than pivot and elements on the right are bigger
-    quick_sort(&mut slice[..pivot_index]);
-    quick_sort(&mut slice[pivot_index + 1..]);
+/// # Description
+/// Sorts only items in `range`(the end is excluded, as in any Rust range) with `quick_sort_by`, items outside of it stay untouched,
+/// e.g. to sort blocks of a slice one by one.
+///
+/// # Complexity
+/// `O(m log m)` on average, where `m` is a length of `range`.
+///
+/// # Panics
+///
+/// Panics if `range` starts after its end or ends after the slice end.
+pub fn quick_sort_range<T: Ord>(slice: &mut [T], range: Range<usize>) {
+    assert!(
+        range.start <= range.end && range.end <= slice.len(),
+        "Range {range:?} is out of bounds"
+    );
+
+    quick_sort_by(&mut slice[range], T::cmp);
 }
 
 /// The goal of this function is find a pivot and move all items which are less(going to call them `low` below) than pivot on the left and all items which are keep in place all other items
 ///
 /// How it's done:
-/// - First, we take a middle element and move it to the end
-///     - We need to move it to the end to make sure that we're going to check all elements except the pivot
+/// - First, we take a median of the first, the middle and the last elements as a pivot(see `median_of_three`) and move it to the end
+///   - Taking a median instead of just a middle element protects us from the worst case `O(n^2)` on already sorted/reverse-sorted or almost sorted slices
+///   - We need to move it to the end to make sure that we're going to check all elements except the pivot
 /// - Then we iterate over rest elements and move `low` items to the left and keep other elements in place.
-///     We don't need to care about other(bigger) elements as they're going to turn on the right anyway(all `low` elements are going to be on the left anyway)
+///   We don't need to care about other(bigger) elements as they're going to turn on the right anyway(all `low` elements are going to be on the left anyway)
 ///     - In iterator we have `left` and `right` indexes
 ///         - `left` is an index of first bigger item from the left. It means that if iterator is over, then `left` is the next index after latest lower item.
 ///         - `right` is an index of an item we're currently checking for being lower or bigger.
 /// - When iterator is over we need to swap latest element with `left`, to "return" the pivot in place. Here's why:
 ///     - the latest element is our pivot, because we swapped it to the end to make sure that all elements are checked.
 ///     - `left` is next after latest lowest element in a slice(or in other words it is first biggest element from the left).
-/// 
+///
 /// After "swap" we now have a pivot element with all lower elements on the left and all bigger element on the right.
-fn partitioning(slice: &mut [i32]) -> usize {
-    let pivot_index = slice.len() / 2;
-    let pivot = slice[pivot_index];
+///
+/// Elements are compared via `cmp`, and the pivot stays at the end till the very last swap, so we compare with it by index and don't need to copy it.
+fn partitioning<T, F>(slice: &mut [T], cmp: &F) -> usize
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    let pivot_index = median_of_three(slice, cmp);
+    let last = slice.len() - 1;
 
-    slice.swap(pivot_index, slice.len() - 1);
+    slice.swap(pivot_index, last);
 
     let mut left = 0;
 
-    for right in 0..slice.len() - 1 {
-        if slice[right] <= pivot {
+    for right in 0..last {
+        if cmp(&slice[right], &slice[last]) != Ordering::Greater {
             slice.swap(left, right);
 
             left += 1;
@@ -46,9 +94,120 @@ fn partitioning(slice: &mut [i32]) -> usize {
     left
 }
 
+/// # Description
+/// Picks a pivot(a median of three) and moves elements, so that everything on the left from the pivot is lower or equal to it
+/// and everything on the right is bigger. Returns the final index of the pivot. It's the same routine which `quick_sort` uses,
+/// so it can be a building block for other selection or sorting algorithms.
+///
+/// # Complexity
+/// `O(n)`.
+///
+/// # Panics
+///
+/// Panics if `slice` is empty, as there is no pivot to pick.
+pub fn partition<T: Ord>(slice: &mut [T]) -> usize {
+    assert!(!slice.is_empty(), "Can't partition an empty slice");
+
+    partitioning(slice, &T::cmp)
+}
+
+/// Returns an index of an element which is the median between the first, the middle and the last elements of a slice.
+/// It's cheap(only 3 comparisons at most), but makes a chance of picking the lowest/biggest element as a pivot much lower.
+fn median_of_three<T, F>(slice: &[T], cmp: &F) -> usize
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    let first = 0;
+    let middle = slice.len() / 2;
+    let last = slice.len() - 1;
+
+    let (low, high) = if cmp(&slice[first], &slice[last]) == Ordering::Greater {
+        (last, first)
+    } else {
+        (first, last)
+    };
+
+    if cmp(&slice[middle], &slice[low]) == Ordering::Less {
+        low
+    } else if cmp(&slice[middle], &slice[high]) == Ordering::Greater {
+        high
+    } else {
+        middle
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::quick_sort;
+    use super::{partition, quick_sort, quick_sort_by, quick_sort_range};
+
+    #[test]
+    fn should_sort_only_range() {
+        // given
+        let mut list = vec![9, 8, 7, 6, 5, 4, 3, 2, 1];
+
+        // when
+        quick_sort_range(&mut list, 3..6);
+
+        // then
+        assert_eq!(vec![9, 8, 7, 4, 5, 6, 3, 2, 1], list);
+
+        quick_sort_range(&mut list, 2..2);
+        quick_sort_range(&mut list, 0..9);
+
+        assert_eq!((1..=9).collect::<Vec<_>>(), list);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn should_panic_if_range_is_out_of_bounds() {
+        quick_sort_range(&mut [3, 2, 1], 1..4);
+    }
+
+    #[test]
+    fn should_partition_around_pivot() {
+        // given
+        let mut arr = vec![9, 4, 7, 1, 7, 3, 8, 2, 7, 5];
+
+        // when
+        let pivot_index = partition(&mut arr);
+
+        // then
+        let pivot = arr[pivot_index];
+
+        assert!(arr[..pivot_index].iter().all(|x| *x <= pivot));
+        assert!(arr[pivot_index + 1..].iter().all(|x| *x > pivot));
+    }
+
+    #[test]
+    #[should_panic(expected = "empty slice")]
+    fn should_panic_on_empty_partition() {
+        let _ = partition::<i32>(&mut []);
+    }
+
+    #[test]
+    fn should_sort_list_desc() {
+        let mut arr = vec![1, 7677, 6, 2, 5, 0, 12, 51, 2, 88];
+
+        quick_sort_by(&mut arr, |a, b| b.cmp(a));
+
+        assert_eq!(arr, vec![7677, 88, 51, 12, 6, 5, 2, 2, 1, 0]);
+    }
+
+    #[test]
+    fn should_sort_structs_by_custom_rule() {
+        // given
+        let mut words = vec!["pear", "fig", "banana", "kiwi", "apple", "plum"];
+
+        // when
+        // Shorter words go first, words of the same length are sorted alphabetically
+        quick_sort_by(&mut words, |a, b| a.len().cmp(&b.len()).then(a.cmp(b)));
+
+        // then
+        assert_eq!(
+            words,
+            vec!["fig", "kiwi", "pear", "plum", "apple", "banana"]
+        );
+    }
 
     #[test]
     fn should_sort_list() {
@@ -69,4 +228,20 @@ mod tests {
             vec![0, 0, 0, 1, 2, 2, 2, 5, 6, 12, 51, 88, 88, 124, 124, 7677]
         );
     }
+    #[test]
+    fn should_sort_already_sorted_list() {
+        let mut arr = (0..10_000).collect::<Vec<i32>>();
+
+        quick_sort(&mut arr);
+
+        assert_eq!(arr, (0..10_000).collect::<Vec<i32>>());
+    }
+    #[test]
+    fn should_sort_reverse_sorted_list() {
+        let mut arr = (0..10_000).rev().collect::<Vec<i32>>();
+
+        quick_sort(&mut arr);
+
+        assert_eq!(arr, (0..10_000).collect::<Vec<i32>>());
+    }
 }
@@ -0,0 +1,102 @@
+use crate::algorithms::numeric::pow_mod;
+
+const BASE: u64 = 256;
+const MODULUS: u64 = 1_000_000_007;
+
+/// # Description
+/// Rabin-Karp finds all occurrences of `needle` in `haystack` and returns their start(byte) indexes in ascending order, overlapping ones included.
+///
+/// Comparing `needle` with every window of `haystack` is `O(n * m)`. Instead, we compare hashes of windows, and a hash of the next window is
+/// calculated from the previous one in `O(1)`("rolling" hash):
+/// 1. A hash is a polynomial of bytes: `h = b0 * BASE^(m-1) + b1 * BASE^(m-2) + ... + b(m-1)` by a big prime modulus.
+/// 2. To move a window by one byte, we remove the first byte(`b0 * BASE^(m-1)`), multiply the rest by `BASE` and add the next byte.
+/// 3. Different windows may have the same hash(a collision), so when hashes are equal, we compare bytes to be sure.
+///
+/// Bytes of a valid UTF-8 needle can match only on char boundaries, so all indexes can be used to slice `haystack`.
+/// An empty `needle` matches at every char boundary, including the end of `haystack`, the same as `str::match_indices`.
+///
+/// # Complexity
+/// `O(n + m)` on average, where `n` is a length of `haystack` and `m` is a length of `needle`. `O(n * m)` in the worst case of many collisions.
+#[must_use]
+pub fn rabin_karp(haystack: &str, needle: &str) -> Vec<usize> {
+    if needle.is_empty() {
+        return (0..=haystack.len())
+            .filter(|&index| haystack.is_char_boundary(index))
+            .collect();
+    }
+
+    let haystack = haystack.as_bytes();
+    let needle = needle.as_bytes();
+
+    if needle.len() > haystack.len() {
+        return vec![];
+    }
+
+    let hash = |bytes: &[u8]| {
+        bytes
+            .iter()
+            .fold(0, |hash, &byte| (hash * BASE + u64::from(byte)) % MODULUS)
+    };
+    // A weight of the first byte of a window, we need it to remove the byte
+    let first_byte_weight = pow_mod(BASE, needle.len() as u64 - 1, MODULUS);
+    let needle_hash = hash(needle);
+    let mut window_hash = hash(&haystack[..needle.len()]);
+    let mut matches = vec![];
+
+    for start in 0..=haystack.len() - needle.len() {
+        if window_hash == needle_hash && &haystack[start..start + needle.len()] == needle {
+            matches.push(start);
+        }
+
+        if let Some(&next_byte) = haystack.get(start + needle.len()) {
+            // `MODULUS` is added before subtraction to stay non-negative
+            let without_first = (window_hash + MODULUS
+                - u64::from(haystack[start]) * first_byte_weight % MODULUS)
+                % MODULUS;
+
+            window_hash = (without_first * BASE + u64::from(next_byte)) % MODULUS;
+        }
+    }
+
+    matches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::rabin_karp;
+
+    #[test]
+    fn should_find_overlapping_matches() {
+        assert_eq!(vec![0, 1, 2], rabin_karp("aaaa", "aa"));
+        assert_eq!(vec![0, 2], rabin_karp("abababa", "ababa"));
+    }
+
+    #[test]
+    fn should_find_all_matches() {
+        // given
+        let haystack = "the quick brown fox jumps over the lazy dog, the end";
+
+        // when
+        let matches = rabin_karp(haystack, "the");
+
+        // then
+        assert_eq!(
+            haystack
+                .match_indices("the")
+                .map(|(index, _)| index)
+                .collect::<Vec<_>>(),
+            matches
+        );
+        // Indexes are in bytes, "ö" and "ß" take 2 bytes each
+        assert_eq!(vec![9], rabin_karp("größer größte", "größte"));
+    }
+
+    #[test]
+    fn should_handle_empty_needle_and_no_match() {
+        assert_eq!(vec![0, 1, 2, 3], rabin_karp("abc", ""));
+        assert_eq!(vec![0], rabin_karp("", ""));
+        assert!(rabin_karp("abc", "abd").is_empty());
+        assert!(rabin_karp("ab", "abc").is_empty());
+        assert!(rabin_karp("", "a").is_empty());
+    }
+}
@@ -0,0 +1,76 @@
+use std::iter;
+
+/// # Description
+/// Run-length encoding replaces every run of equal consecutive items with one pair `(item, run length)`,
+/// e.g. `[1, 1, 1, 2, 3, 3]` becomes `[(1, 3), (2, 1), (3, 2)]`. It compresses well data with long runs(e.g. simple images),
+/// but makes data without runs twice bigger.
+///
+/// Lengths of runs are never `0`, and consecutive pairs never have equal items, so every list has exactly one encoding.
+///
+/// # Complexity
+/// `O(n)`
+#[must_use]
+pub fn rle_encode<T>(slice: &[T]) -> Vec<(T, usize)>
+where
+    T: Eq + Copy,
+{
+    let mut runs: Vec<(T, usize)> = vec![];
+
+    for &item in slice {
+        match runs.last_mut() {
+            Some((last_item, len)) if *last_item == item => *len += 1,
+            _ => runs.push((item, 1)),
+        }
+    }
+
+    runs
+}
+
+/// # Description
+/// Restores a list encoded with `rle_encode`: every pair `(item, run length)` becomes `run length` copies of `item`.
+/// Pairs with `0` length are just skipped.
+///
+/// # Complexity
+/// `O(n)`, where `n` is a length of the decoded list.
+#[must_use]
+pub fn rle_decode<T>(runs: &[(T, usize)]) -> Vec<T>
+where
+    T: Copy,
+{
+    let mut items = Vec::with_capacity(runs.iter().map(|(_, len)| len).sum());
+
+    for &(item, len) in runs {
+        items.extend(iter::repeat_n(item, len));
+    }
+
+    items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{rle_decode, rle_encode};
+
+    #[test]
+    fn should_encode_and_decode_back() {
+        // given
+        let list = [1, 1, 1, 2, 3, 3];
+
+        // when
+        let runs = rle_encode(&list);
+
+        // then
+        assert_eq!(vec![(1, 3), (2, 1), (3, 2)], runs);
+        assert_eq!(list.to_vec(), rle_decode(&runs));
+    }
+
+    #[test]
+    fn should_round_trip_without_runs_and_empty_list() {
+        let chars: Vec<char> = "abcaab".chars().collect();
+
+        assert_eq!(chars, rle_decode(&rle_encode(&chars)));
+        assert_eq!(5, rle_encode(&chars).len());
+        assert!(rle_encode::<i32>(&[]).is_empty());
+        assert!(rle_decode::<i32>(&[]).is_empty());
+        assert_eq!(vec![7, 7], rle_decode(&[(7, 2), (8, 0)]));
+    }
+}
@@ -49,12 +49,12 @@ mod tests {
     }
     impl PartialEq<Self> for Book {
         fn eq(&self, other: &Self) -> bool {
-            &self.pages == &other.pages
+            self.pages == other.pages
         }
     }
     impl PartialOrd for Book {
         fn partial_cmp(&self, other: &Book) -> Option<Ordering> {
-            if &self.pages > &other.pages {
+            if self.pages > other.pages {
                 Some(Ordering::Greater)
             } else {
                 Some(Ordering::Less)
@@ -0,0 +1,61 @@
+/// # Description
+/// Returns all primes lower or equal to `n` in ascending order using the sieve of Eratosthenes.
+///
+/// Realisation details:
+/// 1. Mark all numbers from `2` to `n` as possible primes.
+/// 2. Take the next number which is still marked, it's a prime, as no lower prime divides it.
+/// 3. Unmark all its multiples, starting from its square, as lower multiples were already unmarked by lower primes.
+/// 4. Stop when a square of the number is bigger than `n`, everything which is still marked is a prime.
+///
+/// Returns an empty vector for `n < 2`.
+///
+/// # Complexity
+/// `O(n log log n)` time and `O(n)` additional memory.
+#[must_use]
+pub fn primes_up_to(n: usize) -> Vec<usize> {
+    if n < 2 {
+        return vec![];
+    }
+
+    let mut is_prime = vec![true; n + 1];
+
+    is_prime[0] = false;
+    is_prime[1] = false;
+
+    let mut number = 2;
+
+    while number * number <= n {
+        if is_prime[number] {
+            for multiple in (number * number..=n).step_by(number) {
+                is_prime[multiple] = false;
+            }
+        }
+
+        number += 1;
+    }
+
+    is_prime
+        .iter()
+        .enumerate()
+        .filter_map(|(number, &is_prime)| is_prime.then_some(number))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::primes_up_to;
+
+    #[test]
+    fn should_find_primes_up_to_30() {
+        assert_eq!(vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29], primes_up_to(30));
+        assert_eq!(vec![2, 3, 5, 7], primes_up_to(7));
+        assert_eq!(1229, primes_up_to(10_000).len());
+    }
+
+    #[test]
+    fn should_return_empty_list_below_2() {
+        assert!(primes_up_to(0).is_empty());
+        assert!(primes_up_to(1).is_empty());
+        assert_eq!(vec![2], primes_up_to(2));
+    }
+}
@@ -0,0 +1,110 @@
+use crate::algorithms::{merge_sort_by, quick_sort_by};
+use std::cell::Cell;
+use std::cmp::Ordering;
+
+/// # Description
+/// Wraps a comparator `cmp`, so that every call of it adds `1` to `counter`. It's a way to see complexity of a sort in numbers:
+/// pass the wrapped comparator to any `*_by` sort and read `counter` after it.
+///
+/// `*_by` sorts take `Fn`, so the counter is a `Cell` which can be changed through a shared reference.
+pub fn counting_cmp<'a, T, F>(counter: &'a Cell<usize>, cmp: F) -> impl Fn(&T, &T) -> Ordering + 'a
+where
+    F: Fn(&T, &T) -> Ordering + 'a,
+{
+    move |first, second| {
+        counter.set(counter.get() + 1);
+        cmp(first, second)
+    }
+}
+
+/// Sorts `slice` with `quick_sort_by` and returns a number of element comparisons it took.
+/// It's `O(n log n)` on average, but a number depends on pivots, so it differs for different lists of the same length.
+pub fn quick_sort_counted<T: Ord>(slice: &mut [T]) -> usize {
+    let counter = Cell::new(0);
+
+    quick_sort_by(slice, counting_cmp(&counter, T::cmp));
+
+    counter.get()
+}
+
+/// Sorts `slice` with `merge_sort_by` and returns a number of element comparisons it took.
+/// `merge_sort_by` doesn't switch to insertion sort on small parts(unlike `merge_sort`), so it's a plain merge sort
+/// which never takes more than `n * log2(n)` comparisons.
+pub fn merge_sort_counted<T: Ord + Clone>(slice: &mut [T]) -> usize {
+    let counter = Cell::new(0);
+
+    merge_sort_by(slice, counting_cmp(&counter, T::cmp));
+
+    counter.get()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{counting_cmp, merge_sort_counted, quick_sort_counted};
+    use crate::algorithms::get_random_list;
+    use crate::merge_sort_by;
+    use std::cell::Cell;
+
+    #[test]
+    fn should_keep_merge_sort_comparisons_within_n_log_n() {
+        // given
+        let mut list = get_random_list(1024, 11, 10_000);
+        let mut expected = list.clone();
+
+        expected.sort_unstable();
+
+        // when
+        let comparisons = merge_sort_counted(&mut list);
+
+        // then
+        assert_eq!(expected, list);
+        // log2(1024) = 10, a merge of two halves takes at most `n - 1` comparisons on each of 10 levels
+        assert!(comparisons <= 1024 * 10);
+        // Every merge takes at least a length of the shorter half, so it's at least `n / 2` on each level
+        assert!(comparisons >= 512 * 10);
+    }
+
+    #[test]
+    fn should_count_quick_sort_comparisons() {
+        // given
+        let mut list = get_random_list(1024, 11, 10_000);
+        let mut expected = list.clone();
+
+        expected.sort_unstable();
+
+        // when
+        let comparisons = quick_sort_counted(&mut list);
+
+        // then
+        assert_eq!(expected, list);
+        assert!(comparisons > 1024);
+        // About `1.39 * n * log2(n)` on average, the median of three pivot makes it even lower
+        assert!(comparisons < 2 * 1024 * 10);
+        assert_eq!(0, quick_sort_counted::<i32>(&mut []));
+    }
+
+    #[test]
+    fn should_count_comparisons_of_any_sort() {
+        // given
+        let counter = Cell::new(0);
+        let mut list: Vec<i32> = (0..128).collect();
+
+        // when
+        list.sort_by(counting_cmp(&counter, |a: &i32, b: &i32| b.cmp(a)));
+
+        // then
+        assert_eq!((0..128).rev().collect::<Vec<_>>(), list);
+        assert!(counter.get() > 0);
+
+        // when
+        counter.set(0);
+        merge_sort_by(
+            &mut list,
+            counting_cmp(&counter, |a: &i32, b: &i32| b.cmp(a)),
+        );
+
+        // then
+        // The list is already sorted, so every merge takes the whole first half before the second one, i.e. `n / 2` comparisons on each of 7 levels
+        assert_eq!(64 * 7, counter.get());
+    }
+}
@@ -0,0 +1,232 @@
+use crate::graph::{BasicGraph, BasicGraphNode, Graph, GraphNode};
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+use std::rc::Rc;
+
+/// Goes depth first through all not yet visited nodes reachable from `node` and calls `on_finish` for a node when all its children are done.
+///
+/// The branch is kept in a `Vec` instead of recursion, so long chains don't overflow the call stack.
+/// Every node of the branch keeps an index of its next child to check, when there are no children left, the node is finished.
+fn visit<T, K, F>(
+    node: &Rc<BasicGraphNode<T, K>>,
+    graph: &BasicGraph<T, K>,
+    visited: &mut HashSet<K>,
+    on_finish: &mut F,
+) where
+    K: Eq + Hash + Clone,
+    F: FnMut(K),
+{
+    let mut branch = vec![(Rc::clone(node), 0)];
+
+    visited.insert(node.id().clone());
+
+    while let Some((node, next_child)) = branch.last_mut() {
+        let child_id = node
+            .nodes()
+            .borrow()
+            .get(*next_child)
+            .map(|child| child.id().clone());
+
+        *next_child += 1;
+
+        let Some(child_id) = child_id else {
+            on_finish(node.id().clone());
+            branch.pop();
+            continue;
+        };
+
+        if visited.contains(&child_id) {
+            continue;
+        }
+
+        if let Some(child) = graph.get(&child_id) {
+            visited.insert(child_id);
+            branch.push((Rc::clone(child), 0));
+        }
+    }
+}
+
+/// # Description
+/// Strongly connected component is a group of nodes where every node is reachable from every other node of the group.
+/// Every node belongs to exactly one component, a node without cycles through it is a component on its own.
+///
+/// This is Kosaraju's algorithm, it runs depth first search twice:
+/// 1. On the original graph to get the order in which nodes are finished(all their children are visited).
+/// 2. On the transposed graph in the reversed finish order. Every search started from a not yet visited node collects exactly one component.
+///
+/// The trick is that the node finished last always belongs to a "source" component, and in the transposed graph
+/// we can't leave this component, as all edges which led out of it now lead into it.
+///
+/// The transposed graph is only a `HashMap` of reversed adjacency lists of ids. `transpose` would build a `BasicGraph`,
+/// but its nodes own each other via `Rc`, so every component with a cycle would never be freed.
+///
+/// # Complexity
+/// `O(n + e)`, where `n` is a number of nodes and `e` is a number of edges.
+pub fn strongly_connected_components<T, K>(graph: &BasicGraph<T, K>) -> Vec<Vec<K>>
+where
+    K: Eq + Hash + Clone,
+{
+    let mut visited = HashSet::with_capacity(graph.len());
+    let mut finish_order = Vec::with_capacity(graph.len());
+
+    for node in graph.nodes() {
+        if !visited.contains(node.id()) {
+            visit(node, graph, &mut visited, &mut |id| finish_order.push(id));
+        }
+    }
+
+    let mut reversed: HashMap<&K, Vec<&K>> = HashMap::with_capacity(graph.len());
+
+    for node in graph.nodes() {
+        for child in node.nodes().borrow().iter() {
+            // Edges to nodes which are not a part of the `graph` are skipped
+            if let Some(child) = graph.get(child.id()) {
+                reversed.entry(child.id()).or_default().push(node.id());
+            }
+        }
+    }
+
+    let mut components = vec![];
+
+    visited.clear();
+
+    for id in finish_order.iter().rev() {
+        if !visited.insert(id.clone()) {
+            continue;
+        }
+
+        let mut component = vec![];
+        let mut stack = vec![id];
+
+        while let Some(id) = stack.pop() {
+            component.push(id.clone());
+
+            for &parent_id in reversed.get(id).into_iter().flatten() {
+                if visited.insert(parent_id.clone()) {
+                    stack.push(parent_id);
+                }
+            }
+        }
+
+        components.push(component);
+    }
+
+    components
+}
+
+#[cfg(test)]
+mod tests {
+    use super::strongly_connected_components;
+    use crate::graph::{BasicGraph, BasicGraphNode, Graph, GraphNode};
+    use std::rc::Rc;
+
+    #[test]
+    fn should_find_components() {
+        // given
+        let mut graph = BasicGraph::new();
+
+        for id in 1..=5 {
+            graph.insert(Rc::new(BasicGraphNode::new(id, (), None)));
+        }
+
+        graph.connect(1, 2);
+        graph.connect(2, 3);
+        graph.connect(3, 1);
+        graph.connect(3, 4);
+        graph.connect(4, 5);
+        graph.connect(5, 4);
+
+        // when
+        let mut components = strongly_connected_components(&graph);
+
+        // then
+        components
+            .iter_mut()
+            .for_each(|component| component.sort_unstable());
+        components.sort_unstable();
+
+        assert_eq!(vec![vec![1, 2, 3], vec![4, 5]], components);
+    }
+
+    #[test]
+    fn should_put_every_node_of_acyclic_graph_into_own_component() {
+        // given
+        let mut graph = BasicGraph::new();
+
+        for id in 1..=3 {
+            graph.insert(Rc::new(BasicGraphNode::new(id, (), None)));
+        }
+
+        graph.connect(1, 2);
+        graph.connect(2, 3);
+
+        // when
+        let components = strongly_connected_components(&graph);
+
+        // then
+        assert_eq!(vec![vec![1], vec![2], vec![3]], components);
+    }
+
+    #[test]
+    fn should_not_keep_nodes_of_cyclic_graph_alive() {
+        // given
+        // Every node keeps a clone of `marker`, so its counter shows how many nodes(and their copies) are alive
+        let marker = Rc::new(());
+        let mut graph = BasicGraph::new();
+
+        for id in 1..=2 {
+            graph.insert(Rc::new(BasicGraphNode::new(id, Rc::clone(&marker), None)));
+        }
+
+        graph.connect(1, 2);
+        graph.connect(2, 1);
+
+        let node = Rc::downgrade(graph.get(&1).unwrap());
+
+        // when
+        let components = strongly_connected_components(&graph);
+
+        // then
+        assert_eq!(1, components.len());
+        assert_eq!(3, Rc::strong_count(&marker));
+
+        // Nodes point to each other, so we break the cycle to drop them
+        for node in graph.nodes() {
+            node.nodes().borrow_mut().clear();
+        }
+
+        drop(graph);
+
+        assert!(node.upgrade().is_none());
+        assert_eq!(1, Rc::strong_count(&marker));
+    }
+
+    #[test]
+    fn should_not_overflow_stack_on_long_chain() {
+        // given
+        let mut graph = BasicGraph::new();
+
+        for id in 0..100_000 {
+            graph.insert(Rc::new(BasicGraphNode::new(id, (), None)));
+        }
+
+        for id in 1..100_000 {
+            graph.connect(id - 1, id);
+        }
+
+        // A back edge from the end to the start makes the whole chain one component
+        graph.connect(99_999, 0);
+
+        // when
+        let components = strongly_connected_components(&graph);
+
+        // then
+        assert_eq!(1, components.len());
+        assert_eq!(100_000, components[0].len());
+
+        // Nodes point to each other, so we break all edges to drop them one by one
+        for node in graph.nodes() {
+            node.nodes().borrow_mut().clear();
+        }
+    }
+}
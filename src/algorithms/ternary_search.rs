@@ -0,0 +1,70 @@
+/// # Description
+/// Ternary search finds a point where a unimodal function(it only grows before its peak and only falls after it) has the maximum value.
+/// To find the minimum, search for the maximum of the negated function, e.g. `ternary_search(|x| -f(x), lo, hi, iterations)`.
+///
+/// # Complexity
+/// `O(iterations)`, every iteration keeps 2/3 of the interval, so after `k` iterations the interval is `(hi - lo) * (2/3)^k` long.
+///
+/// # Explanation
+/// Unlike binary search we don't look for a specific value, so one point in the middle doesn't tell us on which side the peak is.
+/// Instead, we take two points `m1` and `m2` which split `[lo, hi]` into three equal parts and compare function values in them:
+/// - if `f(m1) < f(m2)`, then the function still grows at `m1`, so the peak can't be on the left from `m1`, we move `lo` to `m1`.
+/// - otherwise the function already falls at `m2`(or the peak is between `m1` and `m2`), so we move `hi` to `m2`.
+///
+/// After all iterations the middle of the remaining interval is returned.
+pub fn ternary_search<F>(f: F, lo: f64, hi: f64, iterations: usize) -> f64
+where
+    F: Fn(f64) -> f64,
+{
+    let mut low = lo;
+    let mut high = hi;
+
+    for _ in 0..iterations {
+        let third = (high - low) / 3.0;
+        let m1 = low + third;
+        let m2 = high - third;
+
+        if f(m1) < f(m2) {
+            low = m1;
+        } else {
+            high = m2;
+        }
+    }
+
+    low + (high - low) / 2.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ternary_search;
+
+    #[test]
+    fn should_find_peak_of_parabola() {
+        // given
+        let parabola = |x: f64| -(x - 2.5).powi(2) + 4.0;
+
+        // when
+        let peak = ternary_search(parabola, -10.0, 10.0, 100);
+
+        // then
+        assert!((peak - 2.5).abs() < 1e-6);
+        assert!((parabola(peak) - 4.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn should_find_minimum_of_negated_function() {
+        let valley = |x: f64| (x + 1.0).powi(2);
+
+        let bottom = ternary_search(|x| -valley(x), -5.0, 5.0, 100);
+
+        assert!((bottom + 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn should_find_peak_on_interval_border() {
+        // The function only grows on the interval, so the peak is its right end
+        let peak = ternary_search(|x| x, 0.0, 1.0, 100);
+
+        assert!((peak - 1.0).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,345 @@
+use crate::algorithms::binary_search::{gallop, partition_point};
+use std::ops::Range;
+
+/// Runs shorter than this are extended with insertion sort, as it's faster than merging on small slices.
+const MIN_RUN: usize = 32;
+/// After this number of wins of one run in a row we switch from one-by-one comparisons to galloping.
+const MIN_GALLOP: usize = 7;
+
+/// # Description
+/// Finds a run(an already sorted part) of `slice` which starts at `start` and returns its length and whether it's descending.
+/// An ascending run is non-decreasing(`a <= b <= c`), a descending run is strictly decreasing(`a > b > c`),
+/// so reversing a descending run never swaps equal elements, and the sort stays stable.
+///
+/// A run is at least one element long, if `start` is not the last index, then it's at least two elements long.
+///
+/// # Panics
+///
+/// Panics if `start` is out of the slice bounds.
+#[must_use]
+pub fn find_run<T: Ord>(slice: &[T], start: usize) -> (usize, bool) {
+    assert!(start < slice.len(), "Start {start} is out of bounds");
+
+    let rest = &slice[start..];
+
+    if rest.len() == 1 {
+        return (1, false);
+    }
+
+    let is_descending = rest[1] < rest[0];
+    let len = rest
+        .windows(2)
+        .take_while(|pair| (pair[1] < pair[0]) == is_descending)
+        .count()
+        + 1;
+
+    (len, is_descending)
+}
+
+/// # Description
+/// TimSort is a merge sort which takes advantage of already sorted parts of real world data(e.g. a sorted list with a few appended items,
+/// or several concatenated sorted lists). A random list is sorted in `O(n log n)`, but a sorted list takes only `n - 1` comparisons.
+/// The sort is stable.
+///
+/// Realisation details:
+/// 1. Go through the slice and find runs with `find_run`. Descending runs are reversed in place.
+/// 2. Short runs are extended till `MIN_RUN` elements with insertion sort.
+/// 3. Runs are pushed to a stack, and the two top runs are merged as long as lengths don't shrink fast enough from the bottom to the top
+///    (`A > B + C` and `B > C`), so merged runs have similar lengths, as in the usual merge sort.
+/// 4. A merge compares the first elements of both runs one by one. When one run wins `MIN_GALLOP` times in a row,
+///    we find with `gallop` how many more elements it wins and take all of them at once.
+/// 5. In the end all runs left in the stack are merged from the top.
+///
+/// Merges don't copy elements, instead they build an order of indexes and then move elements to their places with swaps, so `T` only needs to be `Ord`.
+///
+/// # Complexity
+/// `O(n log n)` in the worst case, `O(n)` for sorted(or reversed) data, and `O(n)` additional memory for merges.
+pub fn tim_sort<T: Ord>(slice: &mut [T]) {
+    let mut runs: Vec<Range<usize>> = vec![];
+    let mut start = 0;
+
+    while start < slice.len() {
+        let (len, is_descending) = find_run(slice, start);
+
+        if is_descending {
+            slice[start..start + len].reverse();
+        }
+
+        let end = (start + len.max(MIN_RUN)).min(slice.len());
+
+        insertion_sort(&mut slice[start..end], len);
+        runs.push(start..end);
+        start = end;
+
+        collapse_runs(slice, &mut runs);
+    }
+
+    while runs.len() > 1 {
+        let index = runs.len() - 2;
+
+        merge_at(slice, &mut runs, index);
+    }
+}
+
+/// Inserts elements of `slice` from `sorted_end` one by one into the sorted prefix `..sorted_end`, the place is found with binary search.
+/// Elements equal to an inserted one stay before it to keep stability.
+fn insertion_sort<T: Ord>(slice: &mut [T], sorted_end: usize) {
+    for index in sorted_end..slice.len() {
+        let position = partition_point(index, |i| slice[i] > slice[index]);
+
+        slice[position..=index].rotate_right(1);
+    }
+}
+
+/// Merges the top runs of the stack till lengths of runs decrease from the bottom to the top at least as fast as Fibonacci numbers.
+fn collapse_runs<T: Ord>(slice: &mut [T], runs: &mut Vec<Range<usize>>) {
+    while runs.len() > 1 {
+        let top = runs.len() - 1;
+
+        if top >= 2 && runs[top - 2].len() <= runs[top - 1].len() + runs[top].len() {
+            // Merge the middle run with the shorter of its neighbours
+            if runs[top - 2].len() < runs[top].len() {
+                merge_at(slice, runs, top - 2);
+            } else {
+                merge_at(slice, runs, top - 1);
+            }
+        } else if runs[top - 1].len() <= runs[top].len() {
+            merge_at(slice, runs, top - 1);
+        } else {
+            break;
+        }
+    }
+}
+
+/// Merges runs at `index` and `index + 1` into one.
+fn merge_at<T: Ord>(slice: &mut [T], runs: &mut Vec<Range<usize>>, index: usize) {
+    let right = runs.remove(index + 1);
+    let left = &mut runs[index];
+
+    merge_runs(&mut slice[left.start..right.end], left.len());
+    left.end = right.end;
+}
+
+/// Merges two adjacent sorted runs `slice[..mid]` and `slice[mid..]` with galloping.
+fn merge_runs<T: Ord>(slice: &mut [T], mid: usize) {
+    // Runs are already in order, e.g. they are parts of one sorted list
+    if slice[mid - 1] <= slice[mid] {
+        return;
+    }
+
+    let (left, right) = slice.split_at(mid);
+    // `order[i]` is an index of an element which has to be at `i` after the merge
+    let mut order = Vec::with_capacity(slice.len());
+    let mut left_index = 0;
+    let mut right_index = 0;
+    let mut left_wins = 0;
+    let mut right_wins = 0;
+
+    while left_index < left.len() && right_index < right.len() {
+        if left_wins >= MIN_GALLOP {
+            // All elements of the left run which are lower than the current right element go first, equal ones are taken one by one below
+            let left_end = gallop(left, &right[right_index], left_index);
+
+            order.extend(left_index..left_end);
+            left_index = left_end;
+            left_wins = 0;
+        } else if right_wins >= MIN_GALLOP {
+            // Only elements of the right run which are strictly lower than the current left element go first, to keep stability
+            let right_end = gallop(right, &left[left_index], right_index);
+
+            order.extend(mid + right_index..mid + right_end);
+            right_index = right_end;
+            right_wins = 0;
+        } else if left[left_index] <= right[right_index] {
+            order.push(left_index);
+            left_index += 1;
+            left_wins += 1;
+            right_wins = 0;
+        } else {
+            order.push(mid + right_index);
+            right_index += 1;
+            right_wins += 1;
+            left_wins = 0;
+        }
+    }
+
+    order.extend(left_index..left.len());
+    order.extend(mid + right_index..slice.len());
+
+    apply_order(slice, &mut order);
+}
+
+/// Moves elements, so that an element from `order[i]` ends up at `i`. Every cycle of the permutation is followed once,
+/// and placed indexes are marked by pointing to themselves, so it's `O(n)` swaps.
+fn apply_order<T>(slice: &mut [T], order: &mut [usize]) {
+    for start in 0..slice.len() {
+        let mut current = start;
+
+        // The element which was at `start` moves along the cycle till its place
+        while order[current] != start && order[current] != current {
+            let next = order[current];
+
+            slice.swap(current, next);
+            order[current] = current;
+            current = next;
+        }
+
+        order[current] = current;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_run, tim_sort};
+    use crate::algorithms::get_random_list;
+    use crate::merge_sort_by;
+    use std::cell::Cell;
+    use std::cmp::Ordering;
+    use std::rc::Rc;
+
+    /// Counts all comparisons of all items which share a counter.
+    #[derive(Clone, Debug)]
+    struct Counted {
+        value: i32,
+        comparisons: Rc<Cell<usize>>,
+    }
+
+    impl PartialEq for Counted {
+        fn eq(&self, other: &Self) -> bool {
+            self.cmp(other) == Ordering::Equal
+        }
+    }
+
+    impl Eq for Counted {}
+
+    impl PartialOrd for Counted {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Counted {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.comparisons.set(self.comparisons.get() + 1);
+            self.value.cmp(&other.value)
+        }
+    }
+
+    #[test]
+    fn should_find_runs() {
+        let list = [1, 2, 2, 5, 4, 3, 1, 1, 7];
+
+        assert_eq!((4, false), find_run(&list, 0));
+        // Equal elements break a descending run
+        assert_eq!((4, true), find_run(&list, 3));
+        assert_eq!((3, false), find_run(&list, 6));
+        assert_eq!((1, false), find_run(&list, 8));
+    }
+
+    #[test]
+    fn should_sort_list() {
+        for len in [0, 1, 2, 31, 32, 33, 100, 1000, 5000] {
+            // given
+            let mut list = get_random_list(len, 7, 1000);
+            let mut expected = list.clone();
+
+            expected.sort_unstable();
+
+            // when
+            tim_sort(&mut list);
+
+            // then
+            assert_eq!(expected, list);
+        }
+    }
+
+    #[test]
+    fn should_sort_reversed_list() {
+        let mut list: Vec<i32> = (0..10_000).rev().collect();
+
+        tim_sort(&mut list);
+
+        assert_eq!((0..10_000).collect::<Vec<i32>>(), list);
+    }
+
+    #[test]
+    fn should_keep_order_of_equal_elements() {
+        // given
+        let mut pairs: Vec<(i32, usize)> = get_random_list(2000, 7, 1000)
+            .into_iter()
+            .map(|value| value % 10)
+            .enumerate()
+            .map(|(index, value)| (value, index))
+            .collect();
+        let mut keys: Vec<_> = pairs.iter().map(|pair| Key(pair.0, pair.1)).collect();
+
+        // when
+        tim_sort(&mut keys);
+        pairs.sort_by_key(|pair| pair.0);
+
+        // then
+        assert_eq!(
+            pairs,
+            keys.iter().map(|key| (key.0, key.1)).collect::<Vec<_>>()
+        );
+    }
+
+    /// Compared only by the first field, the second one is an original index
+    #[derive(Debug)]
+    struct Key(i32, usize);
+
+    impl PartialEq for Key {
+        fn eq(&self, other: &Self) -> bool {
+            self.0 == other.0
+        }
+    }
+
+    impl Eq for Key {}
+
+    impl PartialOrd for Key {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Key {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.0.cmp(&other.0)
+        }
+    }
+
+    #[test]
+    fn should_compare_less_than_merge_sort_on_partially_sorted_list() {
+        // given
+        // Several sorted lists concatenated together with a few random items at the end
+        let values: Vec<i32> = (0..2000)
+            .chain(500..1500)
+            .chain((0..3000).rev())
+            .chain(get_random_list(50, 7, 1000))
+            .collect();
+        let tim_sort_comparisons = Rc::new(Cell::new(0));
+        let merge_sort_comparisons = Rc::new(Cell::new(0));
+        let to_counted = |comparisons: &Rc<Cell<usize>>| -> Vec<Counted> {
+            values
+                .iter()
+                .map(|&value| Counted {
+                    value,
+                    comparisons: Rc::clone(comparisons),
+                })
+                .collect()
+        };
+        let mut tim_sorted = to_counted(&tim_sort_comparisons);
+        let mut merge_sorted = to_counted(&merge_sort_comparisons);
+
+        // when
+        tim_sort(&mut tim_sorted);
+        merge_sort_by(&mut merge_sorted, Counted::cmp);
+
+        // then
+        assert_eq!(
+            merge_sorted.iter().map(|x| x.value).collect::<Vec<_>>(),
+            tim_sorted.iter().map(|x| x.value).collect::<Vec<_>>()
+        );
+        assert!(tim_sort_comparisons.get() * 2 < merge_sort_comparisons.get());
+    }
+}
@@ -0,0 +1,70 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// # Description
+/// Returns `k` largest items of `slice`, the largest goes first. If `k` is bigger than the slice length, then all items are returned.
+/// Equal items are kept as many times as they appear, e.g. `top_k(&[5, 5, 1], 2)` is `[5, 5]`.
+///
+/// Realisation details:
+/// 1. Keep `k` largest items seen so far in a min-heap(`BinaryHeap` of `Reverse` items), so the smallest of them is on top.
+/// 2. Every next item replaces the top only if it's bigger, the same way as `k_nearest_neighbor` keeps the nearest neighbors.
+/// 3. In the end the heap is sorted, `Reverse` items in ascending order are the original items in descending order.
+///
+/// Sorting the whole slice would be `O(n log n)`, whereas the heap never has more than `k` items.
+///
+/// # Complexity
+/// `O(n log k)` time and `O(k)` additional space.
+#[must_use]
+pub fn top_k<T: Ord + Clone>(slice: &[T], k: usize) -> Vec<T> {
+    let mut heap = BinaryHeap::with_capacity(k.min(slice.len()));
+
+    for item in slice {
+        if heap.len() < k {
+            heap.push(Reverse(item.clone()));
+        } else if let Some(mut smallest_item) = heap.peek_mut() {
+            if smallest_item.0 < *item {
+                *smallest_item = Reverse(item.clone());
+            }
+        }
+    }
+
+    heap.into_sorted_vec()
+        .into_iter()
+        .map(|Reverse(item)| item)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::top_k;
+
+    #[test]
+    fn should_return_k_largest_items() {
+        let list = [4, 9, 1, 7, 3, 9, 2];
+
+        assert_eq!(vec![9, 9, 7], top_k(&list, 3));
+        assert_eq!(vec![9], top_k(&list, 1));
+        assert!(top_k(&list, 0).is_empty());
+    }
+
+    #[test]
+    fn should_return_all_items_if_k_is_not_smaller_than_len() {
+        let list = ["b", "d", "a", "c"];
+
+        assert_eq!(vec!["d", "c", "b", "a"], top_k(&list, 4));
+        assert_eq!(vec!["d", "c", "b", "a"], top_k(&list, 10));
+        assert!(top_k::<i32>(&[], 3).is_empty());
+    }
+
+    #[test]
+    fn should_match_full_sort() {
+        // given
+        let list: Vec<u32> = (0..1000).map(|x| (x * 7919) % 1009).collect();
+        let mut sorted = list.clone();
+
+        sorted.sort_unstable_by(|a, b| b.cmp(a));
+
+        // then
+        assert_eq!(sorted[..50], top_k(&list, 50));
+    }
+}
@@ -0,0 +1,85 @@
+use crate::graph::{BasicGraph, BasicGraphNode, Graph, GraphNode};
+use std::hash::Hash;
+use std::rc::Rc;
+
+/// # Description
+/// Transposing(or reversing) a directed graph means building a new graph with the same nodes, but with every edge pointing to the opposite direction.
+/// So if there is an edge `A -> B` in the original graph, then there is an edge `B -> A` in the transposed one.
+///
+/// It's useful when we need to answer "who can reach this node" questions(reverse reachability) and it's a building block for strongly connected components algorithms.
+///
+/// Values are cloned into new nodes, so the transposed graph is completely independent of the original one.
+/// Edges to nodes which are not a part of the `graph` are skipped.
+///
+/// # Complexity
+/// `O(n + e)`, where `n` is a number of nodes and `e` is a number of edges.
+pub fn transpose<T, K>(graph: &BasicGraph<T, K>) -> BasicGraph<T, K>
+where
+    T: Clone,
+    K: Eq + Hash + Clone,
+{
+    let mut transposed = BasicGraph::new();
+
+    for node in graph.nodes() {
+        transposed.insert(Rc::new(BasicGraphNode::new(
+            node.id().clone(),
+            node.value().clone(),
+            None,
+        )));
+    }
+
+    for node in graph.nodes() {
+        for child in node.nodes().borrow().iter() {
+            if transposed.contains(child.id()) {
+                transposed.connect(child.id().clone(), node.id().clone());
+            }
+        }
+    }
+
+    transposed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::transpose;
+    use crate::graph::{BasicGraph, BasicGraphNode, Graph, GraphNode};
+    use std::rc::Rc;
+
+    fn child_ids(graph: &BasicGraph<&str>, id: i32) -> Vec<i32> {
+        let mut ids = graph
+            .get(&id)
+            .unwrap()
+            .nodes()
+            .borrow()
+            .iter()
+            .map(|node| *node.id())
+            .collect::<Vec<_>>();
+
+        ids.sort_unstable();
+        ids
+    }
+
+    #[test]
+    fn should_reverse_edges() {
+        // given
+        let mut graph = BasicGraph::new();
+
+        graph.insert(Rc::new(BasicGraphNode::new(1, "one", None)));
+        graph.insert(Rc::new(BasicGraphNode::new(2, "two", None)));
+        graph.insert(Rc::new(BasicGraphNode::new(3, "three", None)));
+
+        graph.connect(1, 2);
+        graph.connect(1, 3);
+        graph.connect(2, 3);
+
+        // when
+        let transposed = transpose(&graph);
+
+        // then
+        assert_eq!(3, transposed.len());
+        assert_eq!(&"two", transposed.get(&2).unwrap().value());
+        assert!(child_ids(&transposed, 1).is_empty());
+        assert_eq!(vec![1], child_ids(&transposed, 2));
+        assert_eq!(vec![1, 2], child_ids(&transposed, 3));
+    }
+}
@@ -0,0 +1,105 @@
+use crate::tree::{BasicTree, BasicTreeNode, Tree, TreeNode};
+use crate::Traversable;
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::ptr;
+use std::rc::{Rc, Weak};
+
+/// # Description
+/// Returns a diameter of a tree: a number of edges on the longest path between any two nodes, e.g. `0` for a single node.
+/// A path may go up to a parent and down to another branch, so it doesn't have to start at the head.
+///
+/// Realisation details:
+/// 1. Go depth first from the head in all directions(to children and to the parent) and find the farthest node.
+///    It's always an end of some longest path.
+/// 2. Go depth first again from that node, the distance to the farthest node from it is the diameter.
+///
+/// Trees don't have cycles, so to not go back we only need to skip the node we came from. A `Vec` is used as a stack
+/// instead of recursion, so long branches don't overflow the call stack.
+///
+/// # Complexity
+/// `O(n)`, where `n` is a number of nodes, every node is visited twice.
+pub fn diameter<V, K>(tree: &BasicTree<V, K>) -> usize
+where
+    K: Eq + Hash + Clone + Debug,
+{
+    let (farthest_node, _) = find_farthest(tree.head());
+    let (_, diameter) = find_farthest(&farthest_node);
+
+    diameter
+}
+
+/// Returns the farthest node from `start` and a number of edges to it.
+fn find_farthest<V, K>(start: &Rc<BasicTreeNode<V, K>>) -> (Rc<BasicTreeNode<V, K>>, usize)
+where
+    K: Eq + Hash,
+{
+    let mut farthest = (Rc::clone(start), 0);
+    // Every item is a node, a node we came from and a distance from `start`
+    let mut stack = vec![(Rc::clone(start), None, 0)];
+
+    while let Some((node, came_from, distance)) = stack.pop() {
+        if distance > farthest.1 {
+            farthest = (Rc::clone(&node), distance);
+        }
+
+        let parent = node.parent().as_ref().and_then(Weak::upgrade);
+        let mut neighbors = node.children();
+
+        neighbors.extend(parent);
+
+        for neighbor in neighbors {
+            if came_from.is_some_and(|came_from| ptr::eq(came_from, Rc::as_ptr(&neighbor))) {
+                continue;
+            }
+
+            stack.push((neighbor, Some(Rc::as_ptr(&node)), distance + 1));
+        }
+    }
+
+    farthest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::diameter;
+    use crate::tree::BasicTree;
+
+    #[test]
+    fn should_find_longest_path_through_head() {
+        // given
+        let mut tree = BasicTree::from_head(1, ());
+
+        // The longest path is 5 -> 3 -> 2 -> 1 -> 4 -> 6 -> 7
+        for (id, parent_id) in [(2, 1), (3, 2), (4, 1), (5, 3), (6, 4), (7, 6), (8, 2)] {
+            tree.insert(id, parent_id, ());
+        }
+
+        // then
+        assert_eq!(6, diameter(&tree));
+    }
+
+    #[test]
+    fn should_find_longest_path_which_does_not_go_through_head() {
+        // given
+        let mut tree = BasicTree::from_head(1, ());
+
+        // The head has a short branch, whereas the longest path is 6 -> 4 -> 3 -> 2 -> 5 -> 7 -> 8 and doesn't touch the head
+        for (id, parent_id) in [
+            (2, 1),
+            (9, 1),
+            (3, 2),
+            (4, 3),
+            (6, 4),
+            (5, 2),
+            (7, 5),
+            (8, 7),
+        ] {
+            tree.insert(id, parent_id, ());
+        }
+
+        // then
+        assert_eq!(6, diameter(&tree));
+        assert_eq!(0, diameter(&BasicTree::from_head(1, ())));
+    }
+}
@@ -0,0 +1,59 @@
+use std::collections::HashMap;
+
+/// # Description
+/// Finds indexes of two numbers which add up to `target`. Every index can be used only once, so `nums[i] + nums[i]` doesn't count,
+/// but two equal numbers on different indexes do. Returns the pair where the second index is the lowest possible, or `None` if there is no such pair.
+///
+/// Realisation details:
+/// 1. Go through numbers and keep already seen numbers with their indexes in a `HashMap`.
+/// 2. For every number check if its complement(`target - number`) was seen before, if so - it's the answer.
+/// 3. Otherwise remember the current number. We check before remembering, so a number is never paired with itself.
+///
+/// Checking all pairs would be `O(n^2)`, the `HashMap` replaces the inner loop with an `O(1)` lookup.
+///
+/// # Complexity
+/// `O(n)` time and `O(n)` additional space.
+#[must_use]
+pub fn two_sum(nums: &[i32], target: i32) -> Option<(usize, usize)> {
+    let mut seen: HashMap<i32, usize> = HashMap::with_capacity(nums.len());
+
+    for (index, &number) in nums.iter().enumerate() {
+        // A complement which doesn't fit into i32 can't be in the list anyway
+        if let Some(complement) = target.checked_sub(number) {
+            if let Some(&complement_index) = seen.get(&complement) {
+                return Some((complement_index, index));
+            }
+        }
+
+        // For duplicates we keep the first index, the second one is found as a complement
+        seen.entry(number).or_insert(index);
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::two_sum;
+
+    #[test]
+    fn should_find_pair() {
+        assert_eq!(Some((0, 1)), two_sum(&[2, 7, 11, 15], 9));
+        assert_eq!(Some((1, 2)), two_sum(&[3, 2, 4], 6));
+    }
+
+    #[test]
+    fn should_not_find_pair() {
+        assert_eq!(None, two_sum(&[1, 2, 3], 7));
+        assert_eq!(None, two_sum(&[], 0));
+        assert_eq!(None, two_sum(&[i32::MIN, 5], i32::MAX));
+    }
+
+    #[test]
+    fn should_use_every_index_once() {
+        // 3 + 3 is the target, but there is only one 3
+        assert_eq!(None, two_sum(&[3, 1], 6));
+        // Duplicated values are on different indexes, so they can be a pair
+        assert_eq!(Some((0, 2)), two_sum(&[3, 1, 3], 6));
+    }
+}
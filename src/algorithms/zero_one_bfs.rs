@@ -0,0 +1,142 @@
+use crate::algorithms::dijkstra_search::build_chain;
+use crate::graph::GraphError;
+use crate::weighted_graph::WeightedGraph;
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+use std::rc::Rc;
+
+/// # Description
+/// 0-1 BFS finds the shortest path in a weighted graph where every edge weights either `0` or `1`.
+/// For such graphs it does the same job as Dijkstra search, but without searching for the cheapest node on every step.
+///
+/// Realisation details:
+/// 1. Instead of a `Queue` we use a double-ended queue(deque), the start node is the only item in it at the beginning.
+/// 2. Take a node from the front of the deque and calculate cost to its children.
+/// 3. If new cost to a child is lower than existing cost, then we update the child's cost and its parent, and:
+///     - put the child to the front of the deque if the edge weights `0`, as the child costs the same as the current node.
+///     - put the child to the back of the deque if the edge weights `1`, as the child belongs to the next "layer".
+/// 4. Repeat 2-3 steps till the deque is empty.
+/// 5. Build a chain from the start to the finish using `parents` `HashMap`.
+///
+/// So the deque always keeps nodes sorted by cost, and the front node is always the cheapest one, exactly what Dijkstra search is looking for.
+///
+/// Returns `Ok(None)` if `start` or `finish` does not exist, or `finish` can't be reached.
+///
+/// # Errors
+/// Returns `GraphError::UnsupportedWeight` if any edge of the graph weights something else than `0` or `1`.
+///
+/// # Complexity
+/// `O(n + e)`, where `n` is a number of nodes and `e` is a number of edges.
+pub fn zero_one_bfs<K>(
+    graph: &WeightedGraph<K>,
+    start: K,
+    finish: K,
+) -> Result<Option<Vec<K>>, GraphError<K>>
+where
+    K: Ord + Hash + Clone + Eq,
+{
+    for node in graph.nodes() {
+        for edge in node.nodes().iter() {
+            if edge.weight() != 0 && edge.weight() != 1 {
+                return Err(GraphError::UnsupportedWeight {
+                    from: node.id().clone(),
+                    to: edge.node().id().clone(),
+                    weight: edge.weight(),
+                });
+            }
+        }
+    }
+
+    let Some(start_node) = graph.get(&start) else {
+        return Ok(None);
+    };
+    let mut cost = HashMap::from([(start, 0)]);
+    let mut parents = HashMap::new();
+    let mut deque = VecDeque::from([Rc::clone(start_node)]);
+
+    while let Some(node) = deque.pop_front() {
+        let current_node_cost = cost[node.id()];
+
+        for edge in node.nodes().iter() {
+            let new_cost_to_child = current_node_cost + edge.weight();
+            let child_id = edge.node().id();
+
+            if cost
+                .get(child_id)
+                .is_some_and(|&current_min_cost_to_child| {
+                    current_min_cost_to_child <= new_cost_to_child
+                })
+            {
+                continue;
+            }
+
+            cost.insert(child_id.clone(), new_cost_to_child);
+            parents.insert(child_id.clone(), node.id().clone());
+
+            if edge.weight() == 0 {
+                deque.push_front(Rc::clone(edge.node()));
+            } else {
+                deque.push_back(Rc::clone(edge.node()));
+            }
+        }
+    }
+
+    if !cost.contains_key(&finish) {
+        return Ok(None);
+    }
+
+    Ok(Some(build_chain(finish, &parents)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::zero_one_bfs;
+    use crate::graph::GraphError;
+    use crate::weighted_graph::WeightedGraph;
+
+    fn get_graph() -> WeightedGraph {
+        let mut graph = WeightedGraph::new();
+
+        for id in 1..=5 {
+            graph.insert(id);
+        }
+
+        graph.connect(1, 2, 1);
+        graph.connect(2, 5, 1);
+        graph.connect(1, 3, 0);
+        graph.connect(3, 4, 0);
+        graph.connect(4, 5, 1);
+
+        graph
+    }
+
+    #[test]
+    fn should_find_shortest_path() {
+        // Both 1 -> 2 -> 5 and 1 -> 3 -> 4 -> 5 are valid, but the second one costs 1 instead of 2
+        assert_eq!(Ok(Some(vec![1, 3, 4, 5])), zero_one_bfs(&get_graph(), 1, 5));
+    }
+
+    #[test]
+    fn should_return_none_if_finish_is_not_reachable() {
+        assert_eq!(Ok(None), zero_one_bfs(&get_graph(), 5, 1));
+        assert_eq!(Ok(None), zero_one_bfs(&get_graph(), 1, 6));
+    }
+
+    #[test]
+    fn should_return_error_for_unsupported_weight() {
+        // given
+        let mut graph = get_graph();
+
+        graph.connect(2, 3, 2);
+
+        // then
+        assert_eq!(
+            Err(GraphError::UnsupportedWeight {
+                from: 2,
+                to: 3,
+                weight: 2
+            }),
+            zero_one_bfs(&graph, 1, 5)
+        );
+    }
+}
@@ -1,7 +1,28 @@
-pub use queue::Queue;
+pub use bloom_filter::BloomFilter;
+pub use dary_heap::DaryHeap;
+pub use fenwick_tree::FenwickTree;
+pub use indexed_min_heap::IndexedMinHeap;
+pub use queue::{Queue, QueueFull};
+pub use ring_buffer::{RingBuffer, RingBufferFull};
+pub use skip_list::SkipList;
+pub use stack::Stack;
+pub use traversable::Traversable;
+pub use trie::Trie;
 
 pub mod binary_search_tree;
+mod bloom_filter;
+mod dary_heap;
+mod fenwick_tree;
 pub mod graph;
+mod indexed_min_heap;
+pub mod interval_tree;
 mod queue;
+mod ring_buffer;
+pub mod segment_tree;
+mod skip_list;
+mod stack;
+pub mod sync_weighted_graph;
+mod traversable;
 pub mod tree;
+mod trie;
 pub mod weighted_graph;
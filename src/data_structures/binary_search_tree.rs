@@ -1,9 +1,13 @@
 #![allow(clippy::module_name_repetitions)]
 
+use crate::Stack;
 use std::cell::{Ref, RefCell};
-use std::collections::HashMap;
+use std::cmp::Ordering;
+use std::collections::{HashMap, VecDeque};
 use std::fmt::Debug;
 use std::hash::Hash;
+use std::marker::PhantomData;
+use std::mem;
 use std::ops::Deref;
 use std::rc::{Rc, Weak};
 
@@ -33,6 +37,8 @@ pub struct BinarySearchTreeNode<V, K> {
     id: K,
     value: V,
     one_side_depth: RefCell<i32>,
+    // A number of nodes in a subtree where this node is the head(including the node itself), it makes `rank`/`select` `O(log n)`
+    subtree_size: RefCell<usize>,
     parent: RefCell<Weak<Self>>,
     nodes: RefCell<[Option<Rc<Self>>; 2]>,
 }
@@ -40,7 +46,7 @@ pub struct BinarySearchTreeNode<V, K> {
 impl<V, K> BinarySearchTreeNode<V, K>
 where
     V: Ord + Eq,
-    K: Eq + Hash + Copy + Debug,
+    K: Eq + Hash + Clone + Debug,
 {
     #[must_use]
     pub fn new(id: K, parent: Weak<Self>, value: V) -> Self {
@@ -48,6 +54,7 @@ where
             id,
             value,
             one_side_depth: RefCell::new(0),
+            subtree_size: RefCell::new(1),
             parent: RefCell::new(parent),
             nodes: RefCell::new([None, None]),
         }
@@ -72,6 +79,23 @@ where
     pub fn parent(&self) -> impl Deref<Target = Weak<Self>> + '_ {
         Ref::map(self.parent.borrow(), |x| x)
     }
+
+    /// A number of nodes in a subtree where this node is the head, including the node itself.
+    #[must_use]
+    pub fn subtree_size(&self) -> usize {
+        *self.subtree_size.borrow()
+    }
+
+    fn child_size(&self, direction: Directions) -> usize {
+        self.nodes.borrow()[direction as usize]
+            .as_ref()
+            .map_or(0, |child| child.subtree_size())
+    }
+
+    fn update_subtree_size(&self) {
+        *self.subtree_size.borrow_mut() =
+            1 + self.child_size(Directions::Left) + self.child_size(Directions::Right);
+    }
 }
 
 /// # Description
@@ -91,15 +115,25 @@ where
 ///
 /// `BinarySearchTree` has `O(log n)` for both search AND inserting, which makes it superfast at all possible operations(insert, search, delete, edit, maybe something else?).
 pub struct AVLTree<V, K> {
-    head: Rc<BinarySearchTreeNode<V, K>>,
+    // It's `None` only when the tree is empty(new or cleared), the next insert makes a new head
+    head: Option<Rc<BinarySearchTreeNode<V, K>>>,
     tree: HashMap<K, Rc<BinarySearchTreeNode<V, K>>>,
 }
 
 impl<V, K> AVLTree<V, K>
 where
     V: Ord + Eq,
-    K: Eq + Hash + Copy + Debug,
+    K: Eq + Hash + Clone + Debug,
 {
+    /// Creates an empty tree without a head, the first inserted node becomes the head.
+    #[must_use]
+    pub fn new() -> Self {
+        AVLTree {
+            head: None,
+            tree: HashMap::new(),
+        }
+    }
+
     #[must_use]
     pub fn from_head(head_id: K, head_value: V) -> Self {
         let mut tree = HashMap::new();
@@ -107,17 +141,28 @@ where
             id: head_id,
             value: head_value,
             one_side_depth: RefCell::new(0),
+            subtree_size: RefCell::new(1),
             parent: RefCell::new(Weak::new()),
             nodes: RefCell::new([None, None]),
         });
 
-        tree.insert(head.id, Rc::clone(&head));
-        Self { head, tree }
+        tree.insert(head.id.clone(), Rc::clone(&head));
+        Self {
+            head: Some(head),
+            tree,
+        }
     }
 
+    ///
+    /// # Panics
+    ///
+    /// Panics if the tree is empty(created by `new`, cleared or all its nodes were removed), as such tree doesn't have a head.
+    /// Check `is_empty` first if the tree may be empty.
     #[must_use]
     pub fn head(&self) -> &Rc<BinarySearchTreeNode<V, K>> {
-        &self.head
+        self.head
+            .as_ref()
+            .expect("An empty tree doesn't have a head")
     }
 
     #[must_use]
@@ -135,15 +180,36 @@ where
         self.tree.is_empty()
     }
 
-    // We don't need to handle possible panic from `self.tree.get(&parent_id).unwrap()` as we check for None `child_node` before assigning its id to `parent_id`
-    #[allow(clippy::missing_panics_doc)]
+    /// Returns a balance factor of every node by its id: a height of the right subtree minus a height of the left subtree.
+    /// A negative factor means the left side is deeper, a positive one means the right side is deeper.
+    /// In a balanced AVL tree all factors are `-1`, `0` or `1`, so it's handy to check the tree state while debugging rotations.
+    #[must_use]
+    pub fn balance_factors(&self) -> HashMap<K, i32> {
+        self.tree
+            .iter()
+            .map(|(id, node)| (id.clone(), *node.one_side_depth.borrow()))
+            .collect()
+    }
+
     pub fn insert(&mut self, id: K, value: V) {
-        let mut parent_id = self.head().id;
+        self.insert_node(Rc::new(BinarySearchTreeNode::new(id, Weak::new(), value)));
+    }
+
+    /// Links a detached `node`(without a parent and children) into the tree, so `merge` can move nodes between trees without recreating them.
+    // We don't need to handle possible panic from `self.tree.get(&parent_id).unwrap()` as we check for None `child_node` before assigning its id to `parent_id`
+    fn insert_node(&mut self, node: Rc<BinarySearchTreeNode<V, K>>) {
+        let Some(head) = self.head.as_ref() else {
+            // An empty tree doesn't have nodes, so the new node becomes the head
+            self.tree.insert(node.id.clone(), Rc::clone(&node));
+            self.head = Some(node);
+            return;
+        };
+        let mut parent_id = head.id.clone();
 
         let (direction, parent) = loop {
             let parent = self.tree.get(&parent_id).unwrap();
             // If a value of a new node is equal or less than a value of a parent, then we're going to insert it on the left(0 index), otherwise on the right(1 index)
-            let direction = if value > parent.value {
+            let direction = if node.value > parent.value {
                 Directions::Right
             } else {
                 Directions::Left
@@ -155,18 +221,386 @@ where
             match child_node {
                 None => break (direction, parent),
                 Some(child_node) => {
-                    parent_id = child_node.id;
+                    parent_id = child_node.id.clone();
                     continue;
                 }
             }
         };
 
-        let node = Rc::new(BinarySearchTreeNode::new(id, Rc::downgrade(parent), value));
+        *node.parent.borrow_mut() = Rc::downgrade(parent);
         parent.nodes.borrow_mut()[direction as usize] = Some(Rc::clone(&node));
-        self.tree.insert(id, Rc::clone(&node));
+        self.tree.insert(node.id.clone(), Rc::clone(&node));
+
+        // All nodes above the new one got one more node in their subtrees.
+        // Rotations don't change a set of nodes under the top rotated node, so sizes above it stay correct after balancing.
+        let mut ancestor = node.parent().upgrade();
+
+        while let Some(ancestor_node) = ancestor {
+            *ancestor_node.subtree_size.borrow_mut() += 1;
+            ancestor = ancestor_node.parent().upgrade();
+        }
+
         self.update_depth(&node);
     }
 
+    /// The same as `insert`, but returns the inserted node, so there is no need in a separate `get`.
+    /// Rotations move nodes by relinking, a node itself stays the same `Rc`, so the returned node already has its final parent and children after balancing.
+    pub fn insert_and_get(&mut self, id: K, value: V) -> &Rc<BinarySearchTreeNode<V, K>> {
+        self.insert(id.clone(), value);
+
+        &self.tree[&id]
+    }
+
+    /// Removes a node by its id and returns it detached from the tree, or `None` if there is no such node.
+    ///
+    /// Realisation details:
+    /// 1. A node with one child(or without children) is just replaced by this child.
+    /// 2. A node with two children is replaced by its in-order successor(the lowest node of the right subtree). Values live in nodes without `RefCell`,
+    ///    so we can't move the successor's value, instead we cut the successor from its place(it doesn't have a left child, so its right child takes its place)
+    ///    and link it to the place of the removed node: it takes children, depth and parent of the removed node, or becomes the `head` if the removed node was the head.
+    /// 3. Sizes are recalculated on the way up from the lowest changed node, then depths are updated and nodes are rotated in `update_removed_depth`.
+    ///
+    /// # Complexity
+    /// `O(log n)`
+    // `unwrap` is called only for the successor's parent, and the successor is never the head, as it's below the removed node
+    #[allow(clippy::missing_panics_doc)]
+    pub fn remove(&mut self, node_id: &K) -> Option<Rc<BinarySearchTreeNode<V, K>>> {
+        let node = self.tree.remove(node_id)?;
+        let parent = node.parent().upgrade();
+        let direction = parent.as_ref().map_or(Directions::Left, |parent_node| {
+            AVLTree::get_directions(parent_node, &node)
+        });
+        let [left, right] = mem::take(&mut *node.nodes.borrow_mut());
+
+        // The lowest node which lost a node in its subtree, and a side where it happened
+        let (shrunk_node, shrunk_direction) = match (left, right) {
+            (Some(left), Some(right)) => {
+                let mut successor = Rc::clone(&right);
+
+                loop {
+                    let Some(lower) = successor.nodes()[Directions::Left as usize].clone() else {
+                        break;
+                    };
+
+                    successor = lower;
+                }
+
+                let shrunk = if Rc::ptr_eq(&successor, &right) {
+                    // The successor keeps its right subtree, it just goes one level up
+                    (Rc::clone(&successor), Directions::Right)
+                } else {
+                    let successor_parent = successor.parent().upgrade().unwrap();
+                    let successor_right =
+                        successor.nodes.borrow_mut()[Directions::Right as usize].take();
+
+                    self.relink(Some(&successor_parent), Directions::Left, successor_right);
+                    self.relink(Some(&successor), Directions::Right, Some(right));
+
+                    (successor_parent, Directions::Left)
+                };
+
+                self.relink(Some(&successor), Directions::Left, Some(left));
+                *successor.one_side_depth.borrow_mut() = *node.one_side_depth.borrow();
+                self.relink(parent.as_ref(), direction, Some(successor));
+
+                (Some(shrunk.0), shrunk.1)
+            }
+            (child, None) | (None, child) => {
+                self.relink(parent.as_ref(), direction, child);
+
+                (parent, direction)
+            }
+        };
+
+        let mut ancestor = shrunk_node.clone();
+
+        while let Some(ancestor_node) = ancestor {
+            ancestor_node.update_subtree_size();
+            ancestor = ancestor_node.parent().upgrade();
+        }
+
+        self.update_removed_depth(shrunk_node, shrunk_direction);
+
+        *node.parent.borrow_mut() = Weak::new();
+        *node.one_side_depth.borrow_mut() = 0;
+        *node.subtree_size.borrow_mut() = 1;
+
+        Some(node)
+    }
+
+    /// Puts `child` to `direction` side of `parent`, or makes it the head if there is no `parent`, and points `child` back to its new parent.
+    fn relink(
+        &mut self,
+        parent: Option<&Rc<BinarySearchTreeNode<V, K>>>,
+        direction: Directions,
+        child: Option<Rc<BinarySearchTreeNode<V, K>>>,
+    ) {
+        if let Some(child_node) = child.as_ref() {
+            *child_node.parent.borrow_mut() = parent.map_or_else(Weak::new, Rc::downgrade);
+        }
+
+        match parent {
+            Some(parent_node) => parent_node.nodes.borrow_mut()[direction as usize] = child,
+            None => self.head = child,
+        }
+    }
+
+    /// Returns a number of values in the tree which are lower than `value`, or `None` if there is no such `value` in the tree.
+    /// In other words, it's an index of `value` in a sorted list of all values.
+    ///
+    /// # Complexity
+    /// `O(log n)`, as every node knows a size of its subtree, we don't need to count nodes on the left, we just take a size of the left subtree.
+    #[must_use]
+    pub fn rank(&self, value: &V) -> Option<usize> {
+        let mut rank = 0;
+        let mut is_found = false;
+        let mut next_node = self.head.clone();
+
+        while let Some(node) = next_node {
+            // Equal values may be on both sides after rotations, so when we find one, we still need to check lower values on the left
+            next_node = if value <= node.value() {
+                is_found |= value == node.value();
+                node.nodes()[Directions::Left as usize].clone()
+            } else {
+                rank += node.child_size(Directions::Left) + 1;
+                node.nodes()[Directions::Right as usize].clone()
+            };
+        }
+
+        is_found.then_some(rank)
+    }
+
+    /// Returns a node which value has a given `rank`, i.e. `rank`-th lowest value(starting from `0`), or `None` if `rank` is out of the tree size.
+    ///
+    /// # Complexity
+    /// `O(log n)`
+    #[must_use]
+    pub fn select(&self, rank: usize) -> Option<Rc<BinarySearchTreeNode<V, K>>> {
+        let mut rank = rank;
+        let mut node = Rc::clone(self.head.as_ref()?);
+
+        loop {
+            let left_size = node.child_size(Directions::Left);
+
+            let next_node = match rank.cmp(&left_size) {
+                Ordering::Equal => return Some(node),
+                Ordering::Less => node.nodes()[Directions::Left as usize].clone(),
+                Ordering::Greater => {
+                    rank -= left_size + 1;
+                    node.nodes()[Directions::Right as usize].clone()
+                }
+            };
+
+            node = next_node?;
+        }
+    }
+
+    /// Returns all values in sorted order. It's an in-order traversal(left subtree, node, right subtree) without recursion:
+    /// 1. Go left as far as possible and push every node on the way to a `Stack`.
+    /// 2. Take a node from the `Stack`, it's the lowest value which is not taken yet.
+    /// 3. Repeat 1-2 steps for the right child of the taken node.
+    ///
+    /// Children live behind a `RefCell`, so to return references which live as long as the tree, we push to the `Stack` the same nodes taken from the tree's `HashMap`.
+    ///
+    /// # Complexity
+    /// `O(n)`, the `Stack` never keeps more nodes than the tree height.
+    #[must_use]
+    pub fn in_order_iterative(&self) -> Vec<&V> {
+        let mut values = Vec::with_capacity(self.len());
+        let mut stack = Stack::new();
+        let mut next_node = self.head.as_ref();
+
+        while next_node.is_some() || !stack.is_empty() {
+            while let Some(node) = next_node {
+                stack.push(node);
+                next_node = self.get_child(node, Directions::Left);
+            }
+
+            if let Some(node) = stack.pop() {
+                values.push(node.value());
+                next_node = self.get_child(node, Directions::Right);
+            }
+        }
+
+        values
+    }
+
+    /// Returns a number of nodes on every level, the head is on level `0`. An empty tree has no levels.
+    /// It's a quick way to see how balanced a tree is: in a balanced tree sizes double from level to level till the last one.
+    ///
+    /// It's a breadth first traversal which goes layer by layer: all nodes of the current level are in a `VecDeque`,
+    /// we count them and replace them with their children. `Queue` requires `Debug` items, so we use `VecDeque` here.
+    ///
+    /// # Complexity
+    /// `O(n)`
+    #[must_use]
+    pub fn level_sizes(&self) -> Vec<usize> {
+        let mut sizes = vec![];
+        let mut level: VecDeque<_> = self.head.iter().cloned().collect();
+
+        while !level.is_empty() {
+            sizes.push(level.len());
+
+            for _ in 0..level.len() {
+                let node = level.pop_front().unwrap();
+
+                level.extend(node.nodes().iter().flatten().cloned());
+            }
+        }
+
+        sizes
+    }
+
+    /// Iterates over nodes in sorted order of values. See `BstIter` for details.
+    #[must_use]
+    pub fn iter(&self) -> BstIter<'_, V, K> {
+        let mut iter = BstIter {
+            stack: Stack::new(),
+            tree: PhantomData,
+        };
+
+        iter.push_left_branch(self.head.clone());
+
+        iter
+    }
+
+    fn get_child(
+        &self,
+        node: &BinarySearchTreeNode<V, K>,
+        direction: Directions,
+    ) -> Option<&Rc<BinarySearchTreeNode<V, K>>> {
+        let child_id = node.nodes()[direction as usize].as_ref()?.id.clone();
+
+        self.tree.get(&child_id)
+    }
+
+    /// Merges two trees into one. Nodes of the smaller tree are re-inserted into the bigger one, so key ranges of trees may overlap.
+    ///
+    /// Nodes are moved as they are, so handles to them(e.g. from `get` or `select`) stay valid and point to nodes of the merged tree.
+    ///
+    /// # Complexity
+    /// `O(m log(n + m))`, where `m` is a size of the smaller tree and `n` is a size of the bigger tree.
+    ///
+    /// # Panics
+    ///
+    /// Panics if both trees have a node with the same id, ids must be unique across both trees. It's checked before any node is moved.
+    #[must_use]
+    pub fn merge(self, other: AVLTree<V, K>) -> AVLTree<V, K> {
+        let (mut bigger, smaller) = if self.len() >= other.len() {
+            (self, other)
+        } else {
+            (other, self)
+        };
+
+        if let Some(id) = smaller.tree.keys().find(|id| bigger.tree.contains_key(id)) {
+            panic!("Node with id {id:?} exists in both trees");
+        }
+
+        for node in smaller.into_sorted_nodes() {
+            bigger.insert_node(node);
+        }
+
+        bigger
+    }
+
+    /// Splits a tree into two trees by a pivot `value`: the first tree gets all nodes with values lower or equal to `value`, the second one gets the rest.
+    /// If there are no nodes for one of sides, then `None` is returned for it instead of an empty tree.
+    ///
+    /// # Complexity
+    /// `O(n log n)`, we take all nodes in sorted order and build two new trees from them.
+    #[must_use]
+    pub fn split(self, value: &V) -> (Option<Self>, Option<Self>) {
+        let mut lower = self.into_sorted_nodes();
+        let higher = lower.split_off(lower.partition_point(|node| node.value() <= value));
+
+        (
+            AVLTree::from_sorted_nodes(&lower),
+            AVLTree::from_sorted_nodes(&higher),
+        )
+    }
+
+    /// Rebuilds the tree into a balanced shape with a minimal height, e.g. if it became skewed because of a bug.
+    /// Nodes are kept, we take all of them in sorted order(`into_sorted_nodes`) and link them middle first(`from_sorted_nodes`).
+    ///
+    /// # Complexity
+    /// `O(n log n)`
+    pub fn rebalance(&mut self) {
+        let tree = mem::take(self);
+
+        if let Some(balanced) = AVLTree::from_sorted_nodes(&tree.into_sorted_nodes()) {
+            *self = balanced;
+        }
+    }
+
+    /// Takes all nodes out of the tree in sorted(by value) order and detaches them from each other, so they can be linked again.
+    /// Nodes are not unwrapped, so nodes which are still held outside of the tree(e.g. after `select`) are taken as well, and such handles stay valid.
+    fn into_sorted_nodes(mut self) -> Vec<Rc<BinarySearchTreeNode<V, K>>> {
+        let mut sorted_nodes = Vec::with_capacity(self.len());
+        let mut stack = vec![];
+        let mut next_node = self.head.take();
+
+        // In-order traversal: we go left as far as possible, then take a node and go to its right child
+        while next_node.is_some() || !stack.is_empty() {
+            while let Some(node) = next_node {
+                next_node = node.nodes()[Directions::Left as usize].clone();
+                stack.push(node);
+            }
+
+            let node = stack.pop().unwrap();
+            next_node = node.nodes()[Directions::Right as usize].clone();
+            sorted_nodes.push(node);
+        }
+
+        for node in &sorted_nodes {
+            *node.nodes.borrow_mut() = [None, None];
+            *node.parent.borrow_mut() = Weak::new();
+            *node.one_side_depth.borrow_mut() = 0;
+            *node.subtree_size.borrow_mut() = 1;
+        }
+
+        sorted_nodes
+    }
+
+    /// Builds a tree from detached nodes sorted by value. A middle node becomes the head and middles of both halves become its children and so on,
+    /// so the tree has a minimal height. Nodes are linked directly instead of `insert`, so there are no rotations at all.
+    fn from_sorted_nodes(nodes: &[Rc<BinarySearchTreeNode<V, K>>]) -> Option<AVLTree<V, K>> {
+        let mut tree = HashMap::with_capacity(nodes.len());
+        let (head, _) = AVLTree::build_balanced(nodes, &Weak::new(), &mut tree);
+
+        Some(AVLTree {
+            head: Some(head?),
+            tree,
+        })
+    }
+
+    /// Links a subtree from sorted nodes and returns its head and height. Depth and size of every node are set on the way back,
+    /// when both children are linked. The lower half is never shorter than the higher one, so heights of children differ by 1 at most.
+    fn build_balanced(
+        nodes: &[Rc<BinarySearchTreeNode<V, K>>],
+        parent: &Weak<BinarySearchTreeNode<V, K>>,
+        tree: &mut HashMap<K, Rc<BinarySearchTreeNode<V, K>>>,
+    ) -> (Option<Rc<BinarySearchTreeNode<V, K>>>, usize) {
+        if nodes.is_empty() {
+            return (None, 0);
+        }
+
+        let middle = nodes.len() / 2;
+        let node = Rc::clone(&nodes[middle]);
+        let node_link = Rc::downgrade(&node);
+
+        let (left, left_height) = AVLTree::build_balanced(&nodes[..middle], &node_link, tree);
+        let (right, right_height) = AVLTree::build_balanced(&nodes[middle + 1..], &node_link, tree);
+
+        *node.parent.borrow_mut() = Weak::clone(parent);
+        *node.one_side_depth.borrow_mut() =
+            i32::from(right_height > left_height) - i32::from(left_height > right_height);
+        *node.nodes.borrow_mut() = [left, right];
+        node.update_subtree_size();
+        tree.insert(node.id.clone(), Rc::clone(&node));
+
+        (Some(node), 1 + left_height.max(right_height))
+    }
+
     fn get_directions(
         parent: &Rc<BinarySearchTreeNode<V, K>>,
         child: &Rc<BinarySearchTreeNode<V, K>>,
@@ -180,6 +614,100 @@ where
         Directions::Right
     }
 
+    /// The same as `update_depth`, but for a subtree which got shorter on `direction` side of `parent`.
+    ///
+    /// We go up while a subtree got shorter: if a parent became unbalanced by `1`, then its deeper side is still the same and its height didn't change.
+    /// Unlike an insert, a rotation doesn't stop us, as it makes a subtree shorter as well, unless the deeper child had both sides of the same height.
+    fn update_removed_depth(
+        &mut self,
+        mut parent: Option<Rc<BinarySearchTreeNode<V, K>>>,
+        mut direction: Directions,
+    ) {
+        while let Some(parent_node) = parent {
+            let new_parent_depth =
+                *parent_node.one_side_depth.borrow() - Directions::get_depth(direction);
+            *parent_node.one_side_depth.borrow_mut() = new_parent_depth;
+
+            if new_parent_depth.abs() == 1 {
+                break;
+            }
+
+            let mut top = Rc::clone(&parent_node);
+
+            if new_parent_depth.abs() == 2 {
+                let deeper_direction = Directions::get_opposite(direction);
+                let side_depth = Directions::get_depth(deeper_direction);
+                let child = Rc::clone(
+                    parent_node.nodes.borrow()[deeper_direction as usize]
+                        .as_ref()
+                        .unwrap(),
+                );
+                let child_depth = *child.one_side_depth.borrow();
+
+                if child_depth == -side_depth {
+                    top = self.rotate_twice(&parent_node, &child, deeper_direction);
+                } else {
+                    self.simple_rotation(&parent_node, deeper_direction);
+                    AVLTree::update_rotated_sizes(&parent_node);
+
+                    if child_depth == 0 {
+                        // Both sides of the child were of the same height, so the rotated subtree keeps its height and leans to the other side
+                        *parent_node.one_side_depth.borrow_mut() = side_depth;
+                        *child.one_side_depth.borrow_mut() = -side_depth;
+                        break;
+                    }
+
+                    top = child;
+                }
+            }
+
+            parent = top.parent().upgrade();
+
+            if let Some(next_parent) = parent.as_ref() {
+                direction = AVLTree::get_directions(next_parent, &top);
+            }
+        }
+    }
+
+    /// Does a double rotation of `first_level_node` and its `child` on `direction` side, sets their depths and returns the new top(the middle node).
+    ///
+    /// `child` goes down to one side and the parent goes down to the other side, they get the children of the middle node.
+    /// So their new depths depend on which child of the middle node was deeper.
+    fn rotate_twice(
+        &mut self,
+        first_level_node: &Rc<BinarySearchTreeNode<V, K>>,
+        child: &Rc<BinarySearchTreeNode<V, K>>,
+        direction: Directions,
+    ) -> Rc<BinarySearchTreeNode<V, K>> {
+        let middle_node = Rc::clone(
+            child.nodes.borrow()[Directions::get_opposite(direction) as usize]
+                .as_ref()
+                .unwrap(),
+        );
+        let middle_depth = *middle_node.one_side_depth.borrow();
+        let side_depth = Directions::get_depth(direction);
+
+        self.double_rotation(first_level_node, direction);
+        // TODO: simple_rotation should be inside double_rotation as it is a part of it(we do left-right/right-left rotation and then left/right rotation)
+        //  But, dues to similar borrowing - they conflict with each other. They work fine when separate though, so I may keep it this way, but it'd be much better to merge them.
+        self.simple_rotation(first_level_node, direction);
+        AVLTree::update_rotated_sizes(first_level_node);
+
+        *first_level_node.one_side_depth.borrow_mut() = if middle_depth == side_depth {
+            -side_depth
+        } else {
+            0
+        };
+        *child.one_side_depth.borrow_mut() = if middle_depth == -side_depth {
+            side_depth
+        } else {
+            0
+        };
+        *middle_node.one_side_depth.borrow_mut() = 0;
+
+        middle_node
+    }
+
     /// We store/update depth to perform balancing if one side depth is deeper than 1 child.
     /// That means if a node has 2 children on one side and 0 children on another side, then we should balance this node.
     ///
@@ -187,6 +715,9 @@ where
     /// 1. `first_level_node` - just inserted node, or the latest child in a chain.
     /// 2. `second_level_node` - parent node of `third_level_node`, or middle node in our 3 items chain
     /// 3. `third_level_node` - parent of `second_level_node`, or the first node in out 3 item chain
+    ///
+    /// We go up only while a subtree got deeper: if a parent became balanced(`0`), then its height didn't change and nodes above it stay the same.
+    /// A rotation also returns a subtree to its height before the insert, so we stop after it as well.
     fn update_depth(&mut self, inserted_node: &Rc<BinarySearchTreeNode<V, K>>) {
         // We need to keep track of a current node(parent) and its child.
         // We need to keep track of them to calculate edge direction(left or right) and additional depth(-1 or 1)
@@ -206,6 +737,10 @@ where
             let new_parent_depth = *parent_node.one_side_depth.borrow() + additional_depth;
             *parent_node.one_side_depth.borrow_mut() = new_parent_depth;
 
+            if new_parent_depth == 0 {
+                break;
+            }
+
             let is_simple_rotation = new_parent_depth >= 2 && child_depth > 0
                 || new_parent_depth <= -2 && child_depth < 0;
             // If 3 items are not aligned in a single line, then we need to align them first via double rotation(do left-right/right-left rotation and then left/right rotation).
@@ -214,14 +749,12 @@ where
 
             if is_simple_rotation {
                 self.simple_rotation(&parent_node, direction);
+                AVLTree::update_rotated_sizes(&parent_node);
                 break;
             }
 
             if is_double_rotation {
-                self.double_rotation(&parent_node, direction);
-                // TODO: simple_rotation should be inside double_rotation as it is a part of it(we do left-right/right-left rotation and then left/right rotation)
-                //  But, dues to similar borrowing - they conflict with each other. They work fine when separate though, so I may keep it this way, but it'd be much better to merge them.
-                self.simple_rotation(&parent_node, direction);
+                self.rotate_twice(&parent_node, &child, direction);
                 break;
             }
 
@@ -230,6 +763,20 @@ where
         }
     }
 
+    /// After a rotation the node which was the top of rotated nodes becomes a child of the new top.
+    /// Only children of the new top and the new top itself got different subtrees, everything below them was moved as a whole.
+    fn update_rotated_sizes(rotated_node: &Rc<BinarySearchTreeNode<V, K>>) {
+        let Some(top) = rotated_node.parent().upgrade() else {
+            return;
+        };
+
+        for child in top.nodes().iter().flatten() {
+            child.update_subtree_size();
+        }
+
+        top.update_subtree_size();
+    }
+
     /// We balance nodes via rotation. Let's say we have nodes `3 -> 2 -> 1`. 3 points to 2(which is located on the left side) and 2 points to 1(which is located on the left side).
     /// To balance them, we can rotate them, i.e. make middle element a parent of the chain and place other items accordingly.
     /// After rotation, we should get this result `3 <- 2 -> 1`, i.e. 2 now is parent, and it points to 3(on the left) and 1(on the right).
@@ -285,7 +832,7 @@ where
             // Our three elements are the only elements in a tree
             None => {
                 *second_level_node.parent.borrow_mut() = Weak::new();
-                self.head = second_level_node;
+                self.head = Some(second_level_node);
             }
             Some(parent_of_three) => {
                 let insert_direction_for_parent_of_three =
@@ -334,10 +881,7 @@ where
                 .unwrap(),
         );
 
-        *first_level_node.one_side_depth.borrow_mut() =
-            Directions::get_depth(balance_direction) * 2;
-        *second_level_node.one_side_depth.borrow_mut() = Directions::get_depth(balance_direction);
-        *third_level_node.one_side_depth.borrow_mut() = 0;
+        // Depths are set by `update_depth` after the following simple rotation, when all three nodes are in their final places
 
         let third_level_node_same_line_child =
             third_level_node.nodes.borrow_mut()[balance_direction as usize].take();
@@ -364,9 +908,641 @@ where
     }
 }
 
+impl<V> AVLTree<V, V>
+where
+    V: Ord + Eq + Hash + Clone + Debug,
+{
+    /// Creates a tree where every value is its own id, e.g. for a set of numbers, so there is no need to come up with ids.
+    /// Ids must be unique, so duplicated values are kept only once.
+    ///
+    /// Values are sorted first and then linked middle first, so the tree is balanced from the beginning.
+    ///
+    /// # Complexity
+    /// `O(n log n)`
+    #[must_use]
+    pub fn from_values(values: &[V]) -> Self {
+        let mut values = values.to_vec();
+
+        values.sort_unstable();
+        values.dedup();
+
+        let nodes: Vec<_> = values
+            .into_iter()
+            .map(|value| Rc::new(BinarySearchTreeNode::new(value.clone(), Weak::new(), value)))
+            .collect();
+
+        AVLTree::from_sorted_nodes(&nodes).unwrap_or_default()
+    }
+}
+
+impl<V, K> Default for AVLTree<V, K>
+where
+    V: Ord + Eq,
+    K: Eq + Hash + Clone + Debug,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// # Description
+/// An in-order iterator over nodes of an `AVLTree`, it's the same traversal as `in_order_iterative`, but lazy.
+///
+/// Children live behind a `RefCell`, so the `Stack` keeps cloned `Rc`s instead of references, and a `RefCell` is borrowed
+/// only for a moment of cloning a child. So no borrow is held between `next` calls, and nodes can be freely used inside a loop.
+/// The iterator borrows the tree itself, so the tree can't be changed(and rebalanced) while it's iterated.
+///
+/// # Complexity
+/// `O(n)` for the whole iteration, the `Stack` never keeps more nodes than the tree height.
+pub struct BstIter<'a, V, K> {
+    stack: Stack<Rc<BinarySearchTreeNode<V, K>>>,
+    tree: PhantomData<&'a AVLTree<V, K>>,
+}
+
+impl<V, K> BstIter<'_, V, K> {
+    /// Pushes `node` and all its left descendants, so the lowest of them is on top of the `Stack`.
+    fn push_left_branch(&mut self, mut node: Option<Rc<BinarySearchTreeNode<V, K>>>) {
+        while let Some(current) = node {
+            node = current.nodes.borrow()[Directions::Left as usize].clone();
+            self.stack.push(current);
+        }
+    }
+}
+
+impl<V, K> Iterator for BstIter<'_, V, K> {
+    type Item = Rc<BinarySearchTreeNode<V, K>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        let right = node.nodes.borrow()[Directions::Right as usize].clone();
+
+        self.push_left_branch(right);
+
+        Some(node)
+    }
+}
+
+// `clear` is used by `Drop`, and `Drop` can't have more bounds than the struct itself, so it's a separate impl without bounds
+impl<V, K> AVLTree<V, K> {
+    /// Removes all nodes from the tree.
+    ///
+    /// Nodes own their children, so if we just dropped the head, then every node would drop its children recursively.
+    /// Instead, we detach children level by level, so every node is dropped alone without going deeper.
+    /// `Queue` requires `Debug` items(it prints dropped ones), so we use `VecDeque` here.
+    ///
+    /// # Complexity
+    /// `O(n)`
+    pub fn clear(&mut self) {
+        let mut deque: VecDeque<_> = self.head.take().into_iter().collect();
+
+        while let Some(node) = deque.pop_front() {
+            let children = mem::take(&mut *node.nodes.borrow_mut());
+
+            deque.extend(children.into_iter().flatten());
+        }
+
+        self.tree.clear();
+    }
+}
+
+impl<V, K> Drop for AVLTree<V, K> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::AVLTree;
+    use super::{AVLTree, BinarySearchTreeNode, Directions};
+    use std::rc::Rc;
+
+    // Checks that all values on the left are lower or equal and all values on the right are bigger or equal, and returns all values in order
+    fn collect_valid_values(node: &Rc<BinarySearchTreeNode<i32, i32>>) -> Vec<i32> {
+        let mut values = vec![];
+
+        if let Some(left) = node.nodes()[Directions::Left as usize].as_ref() {
+            values.extend(collect_valid_values(left));
+        }
+
+        values.push(*node.value());
+
+        if let Some(right) = node.nodes()[Directions::Right as usize].as_ref() {
+            values.extend(collect_valid_values(right));
+        }
+
+        assert!(values.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert_eq!(values.len(), node.subtree_size());
+
+        values
+    }
+
+    fn height(node: &Rc<BinarySearchTreeNode<i32, i32>>) -> usize {
+        1 + node.nodes().iter().flatten().map(height).max().unwrap_or(0)
+    }
+
+    #[test]
+    fn should_rebalance_skewed_tree() {
+        // given
+        // AVL inserts keep a tree balanced, so we build a skewed tree(a chain of right children) manually
+        let mut tree = AVLTree::from_head(0, 0);
+        let mut last = Rc::clone(tree.head());
+
+        for id in 1..100 {
+            let node = Rc::new(BinarySearchTreeNode::new(id, Rc::downgrade(&last), id));
+
+            last.nodes.borrow_mut()[Directions::Right as usize] = Some(Rc::clone(&node));
+            tree.tree.insert(id, Rc::clone(&node));
+            last = node;
+        }
+
+        drop(last);
+
+        assert_eq!(100, height(tree.head()));
+
+        // when
+        tree.rebalance();
+
+        // then
+        // 100 nodes fit into 7 levels(up to 127 nodes), but not into 6(up to 63 nodes)
+        assert_eq!(7, height(tree.head()));
+        assert_eq!(100, tree.len());
+        assert_eq!(
+            (0..100).collect::<Vec<_>>(),
+            collect_valid_values(tree.head())
+        );
+        assert_eq!(&42, tree.get(&42).unwrap().value());
+    }
+
+    #[test]
+    fn should_keep_held_nodes_on_rebalance() {
+        // given
+        let mut tree = AVLTree::from_head(0, 0);
+
+        for id in 1..10 {
+            tree.insert(id, id * 10);
+        }
+
+        let held = tree.select(3).unwrap();
+
+        // when
+        tree.rebalance();
+
+        // then
+        assert_eq!(10, tree.len());
+        assert_eq!(
+            (0..10).map(|value| value * 10).collect::<Vec<_>>(),
+            collect_valid_values(tree.head())
+        );
+        assert!(Rc::ptr_eq(&held, tree.get(&3).unwrap()));
+        assert_eq!(Some(3), tree.rank(held.value()));
+    }
+
+    #[test]
+    fn should_keep_balance_factors_in_range_after_inserts() {
+        // given
+        let mut tree = AVLTree::from_head(0, 500);
+
+        // when
+        // Values jump back and forth, so both simple and double rotations happen on both sides
+        for id in 1..1000 {
+            tree.insert(id, (id * 7919) % 1009);
+        }
+
+        // then
+        let factors = tree.balance_factors();
+
+        assert_eq!(1000, factors.len());
+        assert!(factors.values().all(|factor| (-1..=1).contains(factor)));
+
+        // Stored factors match real heights of subtrees
+        for node in tree.iter() {
+            let [left, right] = node
+                .nodes()
+                .clone()
+                .map(|child| child.as_ref().map_or(0, height));
+            let expected = i32::try_from(right).unwrap() - i32::try_from(left).unwrap();
+
+            assert_eq!(expected, factors[node.id()]);
+        }
+
+        // 1000 nodes in an AVL tree are at most 1.44 * log2(1000) ≈ 14 levels deep
+        assert!(height(tree.head()) <= 14);
+    }
+
+    #[test]
+    fn should_return_inserted_node_after_rotation() {
+        // given
+        let mut tree = AVLTree::from_head(1, 10);
+
+        tree.insert(2, 20);
+
+        // when
+        // 10 -> 20 -> 30 is a chain, so 20 becomes the head and 30 its right child
+        let node = tree.insert_and_get(3, 30);
+
+        // then
+        assert_eq!(&30, node.value());
+        assert_eq!(&3, node.id());
+        assert_eq!(&2, node.parent().upgrade().unwrap().id());
+
+        let node = Rc::clone(node);
+
+        assert!(Rc::ptr_eq(&node, tree.get(&3).unwrap()));
+        assert_eq!(&2, tree.head().id());
+    }
+
+    #[test]
+    fn should_build_tree_from_empty_one() {
+        // given
+        let mut tree = AVLTree::new();
+
+        assert!(tree.is_empty());
+        assert!(tree.iter().next().is_none());
+
+        // when
+        tree.insert("first", 10);
+
+        // then
+        assert_eq!(&"first", tree.head().id());
+        assert_eq!(1, tree.len());
+
+        // when
+        for (id, value) in [("second", 20), ("third", 30), ("fourth", 5)] {
+            tree.insert(id, value);
+        }
+
+        // then
+        // 10 -> 20 -> 30 was rotated, so 20 is the head now
+        assert_eq!(&20, tree.head().value());
+        assert_eq!(vec![&5, &10, &20, &30], tree.in_order_iterative());
+        assert_eq!(Some(1), tree.rank(&10));
+    }
+
+    #[test]
+    #[should_panic(expected = "doesn't have a head")]
+    fn should_panic_on_head_of_empty_tree() {
+        let tree: AVLTree<i32, i32> = AVLTree::default();
+
+        let _ = tree.head();
+    }
+
+    // Checks that every child points back to its parent and stored balance factors match real heights of subtrees
+    fn assert_links_and_factors(tree: &AVLTree<i32, i32>) {
+        let factors = tree.balance_factors();
+
+        for node in tree.iter() {
+            for child in node.nodes().iter().flatten() {
+                assert!(Rc::ptr_eq(&node, &child.parent().upgrade().unwrap()));
+            }
+
+            let [left, right] = node
+                .nodes()
+                .clone()
+                .map(|child| child.as_ref().map_or(0, height));
+            let expected = i32::try_from(right).unwrap() - i32::try_from(left).unwrap();
+
+            assert_eq!(expected, factors[node.id()]);
+            assert!((-1..=1).contains(&expected));
+        }
+    }
+
+    #[test]
+    fn should_remove_head_of_balanced_tree() {
+        // given
+        let mut tree = AVLTree::from_values(&(1..=15).collect::<Vec<_>>());
+
+        assert_eq!(&8, tree.head().id());
+
+        // when
+        let removed = tree.remove(&8).unwrap();
+
+        // then
+        // 9 is the in-order successor of 8, so it takes the place of the head
+        assert_eq!(&9, tree.head().id());
+        assert!(tree.head().parent().upgrade().is_none());
+        assert!(removed.parent().upgrade().is_none());
+        assert!(removed.nodes().iter().all(Option::is_none));
+        assert_eq!(&8, removed.value());
+        assert_eq!(14, tree.len());
+        assert!(tree.get(&8).is_none());
+        assert!(tree.remove(&8).is_none());
+        assert_eq!(
+            (1..=15).filter(|&value| value != 8).collect::<Vec<_>>(),
+            collect_valid_values(tree.head())
+        );
+        assert_links_and_factors(&tree);
+        assert_eq!(4, height(tree.head()));
+    }
+
+    #[test]
+    fn should_keep_tree_balanced_after_removes() {
+        // given
+        let mut tree = AVLTree::from_head(0, 500);
+
+        for id in 1..1000 {
+            tree.insert(id, (id * 7919) % 1009);
+        }
+
+        // when
+        // Every removal of the head replaces it with a successor, other removals go from both sides
+        for id in (0..1000).step_by(3) {
+            let head_id = *tree.head().id();
+
+            tree.remove(&id);
+            tree.remove(&head_id);
+        }
+
+        // then
+        assert_links_and_factors(&tree);
+        assert_eq!(tree.len(), collect_valid_values(tree.head()).len());
+        assert_eq!(tree.len(), tree.iter().count());
+
+        // when
+        let ids: Vec<_> = tree.iter().map(|node| *node.id()).collect();
+
+        for id in ids {
+            tree.remove(&id);
+        }
+
+        // then
+        assert!(tree.is_empty());
+        assert!(tree.iter().next().is_none());
+    }
+
+    #[test]
+    fn should_create_balanced_tree_from_values() {
+        // when
+        let tree = AVLTree::from_values(&[1, 2, 3, 4, 5]);
+
+        // then
+        assert_eq!(5, tree.len());
+        assert_eq!(3, height(tree.head()));
+        assert_eq!(vec![1, 2, 3, 4, 5], collect_valid_values(tree.head()));
+        assert_eq!(&4, tree.get(&4).unwrap().value());
+        assert_eq!(Some(2), tree.rank(&3));
+    }
+
+    #[test]
+    fn should_iterate_large_tree_without_borrow_conflicts() {
+        // given
+        let mut tree = AVLTree::from_head(0, 0);
+
+        for id in 1..10_000 {
+            tree.insert(id, (id * 7919) % 10_007);
+        }
+
+        // when
+        let mut values = vec![];
+
+        for node in tree.iter() {
+            // Children of the current node and of its neighbours can be borrowed while iterating
+            let _children = node.nodes();
+
+            assert_eq!(Some(values.len()), tree.rank(node.value()));
+
+            values.push(*node.value());
+        }
+
+        // then
+        assert_eq!(10_000, values.len());
+        assert!(values.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(tree.in_order_iterative(), values.iter().collect::<Vec<_>>());
+
+        tree.clear();
+
+        assert!(tree.iter().next().is_none());
+    }
+
+    #[test]
+    fn should_traverse_in_order_without_recursion() {
+        // given
+        let mut tree = AVLTree::from_values(&[8, 4, 12, 2, 6, 10, 14, 1, 3, 5, 7, 9, 11, 13, 15]);
+
+        tree.insert(16, 0);
+
+        // when
+        let values: Vec<i32> = tree.in_order_iterative().into_iter().copied().collect();
+
+        // then
+        assert_eq!(collect_valid_values(tree.head()), values);
+        assert_eq!(16, values.len());
+
+        tree.clear();
+
+        assert!(tree.in_order_iterative().is_empty());
+    }
+
+    #[test]
+    fn should_count_nodes_on_every_level() {
+        // given
+        let mut tree = AVLTree::from_values(&(1..=15).collect::<Vec<_>>());
+
+        // then
+        assert_eq!(vec![1, 2, 4, 8], tree.level_sizes());
+
+        // when
+        tree.insert(16, 16);
+        tree.insert(17, 17);
+
+        // then
+        // 15 -> 16 -> 17 is rotated, so the tree gets only one more level
+        assert_eq!(vec![1, 2, 4, 8, 2], tree.level_sizes());
+
+        tree.clear();
+
+        assert!(tree.level_sizes().is_empty());
+    }
+
+    #[test]
+    fn should_keep_duplicated_values_once() {
+        let tree = AVLTree::from_values(&[3, 1, 3, 2, 1]);
+
+        assert_eq!(3, tree.len());
+        assert_eq!(2, height(tree.head()));
+        assert!(AVLTree::<i32, i32>::from_values(&[]).is_empty());
+    }
+
+    #[test]
+    fn should_clear_large_tree() {
+        // given
+        let mut tree = AVLTree::from_head(0, 0);
+
+        for id in 1..100_000 {
+            tree.insert(id, id);
+        }
+
+        let deepest_node = Rc::downgrade(tree.get(&99_999).unwrap());
+
+        // when
+        tree.clear();
+
+        // then
+        assert!(tree.is_empty());
+        assert!(deepest_node.upgrade().is_none());
+        assert_eq!(None, tree.rank(&5));
+
+        tree.insert(1, 10);
+
+        assert_eq!(&10, tree.head().value());
+        assert_eq!(1, tree.len());
+    }
+
+    #[test]
+    fn should_merge_trees() {
+        // given
+        let mut first = AVLTree::from_head(1, 10);
+        let mut second = AVLTree::from_head(10, 15);
+
+        first.insert(2, 20);
+        first.insert(3, 30);
+        second.insert(11, 5);
+        second.insert(12, 25);
+        second.insert(13, 35);
+
+        // when
+        let merged = first.merge(second);
+
+        // then
+        assert_eq!(7, merged.len());
+        assert_eq!(
+            vec![5, 10, 15, 20, 25, 30, 35],
+            collect_valid_values(merged.head())
+        );
+        assert_eq!(Some(3), merged.rank(&20));
+    }
+
+    #[test]
+    fn should_split_tree() {
+        // given
+        let mut tree = AVLTree::from_head(0, 0);
+
+        for id in 1..10 {
+            tree.insert(id, id * 10);
+        }
+
+        // when
+        let (lower, higher) = tree.split(&40);
+        let (lower, higher) = (lower.unwrap(), higher.unwrap());
+
+        // then
+        assert_eq!(vec![0, 10, 20, 30, 40], collect_valid_values(lower.head()));
+        assert_eq!(
+            vec![50, 60, 70, 80, 90],
+            collect_valid_values(higher.head())
+        );
+        assert_eq!(5, lower.len());
+        assert!(higher.get(&5).is_some());
+        assert!(lower.get(&5).is_none());
+    }
+
+    #[test]
+    fn should_keep_held_nodes_on_merge_and_split() {
+        // given
+        let mut first = AVLTree::from_head(0, 0);
+        let mut second = AVLTree::from_head(100, 5);
+
+        for id in 1..10 {
+            first.insert(id, id * 10);
+            second.insert(100 + id, id * 10 + 5);
+        }
+
+        let held = first.select(3).unwrap();
+        let held_from_iter = second.iter().last().unwrap();
+
+        // when
+        let merged = first.merge(second);
+
+        // then
+        assert_eq!(20, merged.len());
+        assert!(Rc::ptr_eq(&held, merged.get(&3).unwrap()));
+        assert!(Rc::ptr_eq(&held_from_iter, merged.get(&109).unwrap()));
+        assert_eq!(
+            (0..20).map(|value| value * 5).collect::<Vec<_>>(),
+            collect_valid_values(merged.head())
+        );
+
+        // when
+        let (lower, higher) = merged.split(&30);
+        let (lower, higher) = (lower.unwrap(), higher.unwrap());
+
+        // then
+        assert_eq!(7, lower.len());
+        assert_eq!(13, higher.len());
+        assert!(Rc::ptr_eq(&held, lower.get(&3).unwrap()));
+        assert!(Rc::ptr_eq(&held_from_iter, higher.get(&109).unwrap()));
+        assert_eq!(7, collect_valid_values(lower.head()).len());
+        assert_eq!(13, collect_valid_values(higher.head()).len());
+    }
+
+    #[test]
+    #[should_panic(expected = "exists in both trees")]
+    fn should_panic_on_merge_with_same_ids() {
+        let mut first = AVLTree::from_head(1, 10);
+        let second = AVLTree::from_head(1, 20);
+
+        first.insert(2, 30);
+
+        let _ = first.merge(second);
+    }
+
+    #[test]
+    fn should_return_none_for_empty_side_of_split() {
+        let mut tree = AVLTree::from_head(1, 10);
+
+        tree.insert(2, 20);
+
+        let (lower, higher) = tree.split(&5);
+
+        assert!(lower.is_none());
+        assert_eq!(2, higher.unwrap().len());
+    }
+
+    #[test]
+    fn should_find_rank_and_select() {
+        // given
+        let mut tree = AVLTree::from_head(0, 25);
+        let mut values = vec![25];
+
+        // Inserting values in a mixed order to trigger both simple and double rotations
+        for id in 1..50 {
+            let value = (id * 37) % 50;
+            tree.insert(id, value);
+            values.push(value);
+        }
+
+        values.sort_unstable();
+
+        // then
+        assert_eq!(50, tree.head().subtree_size());
+
+        for (rank, value) in values.iter().enumerate() {
+            // Head and id 25 have the same value, a rank of equal values is a position of the first one
+            let first_rank = values.partition_point(|x| x < value);
+
+            assert_eq!(Some(first_rank), tree.rank(value));
+            assert_eq!(value, tree.select(rank).unwrap().value());
+        }
+
+        assert_eq!(None, tree.rank(&100));
+        assert!(tree.select(50).is_none());
+    }
+
+    #[test]
+    fn should_count_duplicates_in_rank() {
+        // given
+        let mut tree = AVLTree::from_head("a", 5);
+
+        tree.insert("b", 5);
+        tree.insert("c", 3);
+        tree.insert("d", 5);
+        tree.insert("e", 7);
+
+        // then
+        assert_eq!(Some(0), tree.rank(&3));
+        assert_eq!(Some(1), tree.rank(&5));
+        assert_eq!(Some(4), tree.rank(&7));
+        assert_eq!(&5, tree.select(3).unwrap().value());
+    }
 
     #[test]
     fn should_assign_nodes_properly() {
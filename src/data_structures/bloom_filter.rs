@@ -0,0 +1,132 @@
+/// # Description
+/// `BloomFilter` answers "was this item inserted" using much less memory than a set, as it keeps only bits, not items.
+/// The price is that it may answer "yes" for an item which was never inserted(a false positive), but it never answers "no" for an inserted item.
+///
+/// Realisation details:
+/// 1. An item is hashed by `k` hash functions into `k` positions of a bit array, and all of them are set on insert.
+/// 2. An item is possibly present only if all its `k` bits are set. Other items may have set the same bits, that's where false positives come from.
+/// 3. Instead of `k` independent hash functions, we calculate two hashes and combine them: `h1 + i * h2` for `i` in `0..k`(double hashing),
+///    it gives the same false positive rate.
+///
+/// More bits make false positives rarer, and the best number of hashes is about `bits / items * ln 2`,
+/// e.g. 10 bits per item with 7 hashes give about 1% of false positives.
+///
+/// # Complexity
+/// `insert` and `contains` are `O(k + m)`, where `k` is a number of hashes and `m` is a length of an item.
+#[derive(Debug)]
+pub struct BloomFilter {
+    bits: Vec<u64>,
+    bits_count: usize,
+    hashes_count: usize,
+}
+
+impl BloomFilter {
+    /// Creates a filter with `bits_count` bits, where every item sets `hashes_count` bits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits_count` or `hashes_count` is `0`.
+    #[must_use]
+    pub fn new(bits_count: usize, hashes_count: usize) -> Self {
+        assert!(bits_count > 0, "Bloom filter needs at least one bit");
+        assert!(hashes_count > 0, "Bloom filter needs at least one hash");
+
+        BloomFilter {
+            bits: vec![0; bits_count.div_ceil(64)],
+            bits_count,
+            hashes_count,
+        }
+    }
+
+    pub fn insert(&mut self, item: &[u8]) {
+        for position in self.positions(item) {
+            self.bits[position / 64] |= 1 << (position % 64);
+        }
+    }
+
+    /// Returns `false` if `item` was never inserted, and `true` if it was inserted or if it's a false positive.
+    #[must_use]
+    pub fn contains(&self, item: &[u8]) -> bool {
+        self.positions(item)
+            .all(|position| self.bits[position / 64] & (1 << (position % 64)) != 0)
+    }
+
+    #[must_use]
+    pub fn bits_count(&self) -> usize {
+        self.bits_count
+    }
+
+    #[must_use]
+    pub fn hashes_count(&self) -> usize {
+        self.hashes_count
+    }
+
+    fn positions(&self, item: &[u8]) -> impl Iterator<Item = usize> {
+        let first_hash = fnv1a(item);
+        // The second hash must not be `0`, otherwise all `k` positions are the same
+        let second_hash = djb2(item) | 1;
+        let bits_count = self.bits_count as u64;
+
+        (0..self.hashes_count as u64).map(move |index| {
+            let hash = first_hash.wrapping_add(index.wrapping_mul(second_hash));
+
+            // The remainder is lower than `bits_count`, which came from `usize`
+            usize::try_from(hash % bits_count).unwrap()
+        })
+    }
+}
+
+/// 64 bit FNV-1a hash: xor a byte into the hash, then multiply by a prime.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// djb2 hash: multiply the hash by 33 and add a byte. The final mixing spreads similar inputs over all bits.
+fn djb2(bytes: &[u8]) -> u64 {
+    let hash = bytes.iter().fold(5381_u64, |hash, &byte| {
+        hash.wrapping_mul(33).wrapping_add(u64::from(byte))
+    });
+
+    (hash ^ (hash >> 33)).wrapping_mul(0xff51_afd7_ed55_8ccd)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BloomFilter;
+
+    #[test]
+    fn should_report_all_inserted_items() {
+        // given
+        let mut filter = BloomFilter::new(10_000, 7);
+
+        // when
+        for index in 0..1000 {
+            filter.insert(format!("item-{index}").as_bytes());
+        }
+
+        // then
+        assert!((0..1000).all(|index| filter.contains(format!("item-{index}").as_bytes())));
+    }
+
+    #[test]
+    fn should_rarely_report_absent_items() {
+        // given
+        // 10 bits per item with 7 hashes give about 1% of false positives
+        let mut filter = BloomFilter::new(10_000, 7);
+
+        for index in 0..1000 {
+            filter.insert(format!("item-{index}").as_bytes());
+        }
+
+        // when
+        let false_positives = (0..10_000)
+            .filter(|index| filter.contains(format!("absent-{index}").as_bytes()))
+            .count();
+
+        // then
+        assert!(false_positives < 300, "{false_positives} false positives");
+        assert!(!BloomFilter::new(64, 3).contains(b"anything"));
+    }
+}
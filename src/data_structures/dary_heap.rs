@@ -0,0 +1,202 @@
+/// # Description
+/// `DaryHeap` is a min-heap(the lowest item is always on top) where every item has up to `d` children instead of 2.
+/// It's stored in a vector: children of an item at index `i` are at indexes `d * i + 1..=d * i + d`, and its parent is at `(i - 1) / d`.
+///
+/// A higher `d` makes the tree lower(`log_d n` levels instead of `log_2 n`), so moving an item up(`push`, decrease key) takes fewer steps,
+/// but moving an item down(`pop`) has to find the lowest of `d` children on every level. So `d = 4` is usually faster for workloads
+/// with many pushes per pop(e.g. Dijkstra search on dense graphs), and it's also friendlier to CPU cache, as children are next to each other.
+/// For balanced push/pop workloads the difference is small, so the default is the classic binary heap(`d = 2`) and `d` is configurable via `with_arity`.
+///
+/// # Complexity
+/// * `push` - `O(log_d n)`
+/// * `pop` - `O(d * log_d n)`
+/// * `peek` - `O(1)`
+#[derive(Debug)]
+pub struct DaryHeap<T> {
+    arity: usize,
+    items: Vec<T>,
+}
+
+impl<T> DaryHeap<T>
+where
+    T: Ord,
+{
+    /// Creates a binary heap(`d = 2`).
+    #[must_use]
+    pub fn new() -> Self {
+        DaryHeap::with_arity(2)
+    }
+
+    /// Creates a heap where every item has up to `arity` children.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `arity` is lower than `2`, as such heap is just a list.
+    #[must_use]
+    pub fn with_arity(arity: usize) -> Self {
+        assert!(arity >= 2, "Arity {arity} is lower than 2");
+
+        DaryHeap {
+            arity,
+            items: vec![],
+        }
+    }
+
+    pub fn push(&mut self, item: T) {
+        self.items.push(item);
+        self.sift_up(self.items.len() - 1);
+    }
+
+    /// Takes the lowest item out of the heap. The last item takes its place and moves down to the right place.
+    pub fn pop(&mut self) -> Option<T> {
+        if self.items.is_empty() {
+            return None;
+        }
+
+        let item = self.items.swap_remove(0);
+
+        self.sift_down(0);
+
+        Some(item)
+    }
+
+    /// Returns the lowest item without taking it.
+    #[must_use]
+    pub fn peek(&self) -> Option<&T> {
+        self.items.first()
+    }
+
+    #[must_use]
+    pub fn arity(&self) -> usize {
+        self.arity
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    fn sift_up(&mut self, index: usize) {
+        let mut index = index;
+
+        while index > 0 {
+            let parent = (index - 1) / self.arity;
+
+            if self.items[parent] <= self.items[index] {
+                break;
+            }
+
+            self.items.swap(parent, index);
+            index = parent;
+        }
+    }
+
+    fn sift_down(&mut self, index: usize) {
+        let mut index = index;
+
+        loop {
+            let first_child = self.arity * index + 1;
+            let children_end = (first_child + self.arity).min(self.items.len());
+
+            // `min_by` takes the first of equal items, the exact choice doesn't matter for a heap
+            let Some(lowest_child) =
+                (first_child..children_end).min_by(|&a, &b| self.items[a].cmp(&self.items[b]))
+            else {
+                break;
+            };
+
+            if self.items[index] <= self.items[lowest_child] {
+                break;
+            }
+
+            self.items.swap(index, lowest_child);
+            index = lowest_child;
+        }
+    }
+}
+
+impl<T> Default for DaryHeap<T>
+where
+    T: Ord,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DaryHeap;
+
+    fn assert_heap_invariant(heap: &DaryHeap<i32>) {
+        for index in 1..heap.items.len() {
+            let parent = (index - 1) / heap.arity;
+
+            assert!(heap.items[parent] <= heap.items[index]);
+        }
+    }
+
+    fn check_heap(arity: usize) {
+        // given
+        let mut heap = DaryHeap::with_arity(arity);
+        let values: Vec<i32> = (0..200).map(|x| (x * 37) % 101 - 50).collect();
+
+        // when
+        for value in &values {
+            heap.push(*value);
+            assert_heap_invariant(&heap);
+        }
+
+        let mut popped = vec![];
+
+        while let Some(value) = heap.pop() {
+            assert_heap_invariant(&heap);
+            popped.push(value);
+        }
+
+        // then
+        let mut sorted = values;
+
+        sorted.sort_unstable();
+
+        assert_eq!(sorted, popped);
+        assert!(heap.is_empty());
+    }
+
+    #[test]
+    fn should_pop_in_sorted_order_with_two_children() {
+        check_heap(2);
+
+        assert_eq!(2, DaryHeap::<i32>::new().arity());
+    }
+
+    #[test]
+    fn should_pop_in_sorted_order_with_four_children() {
+        check_heap(4);
+    }
+
+    #[test]
+    fn should_peek_lowest_item() {
+        let mut heap = DaryHeap::with_arity(3);
+
+        assert_eq!(None, heap.peek());
+
+        heap.push(5);
+        heap.push(1);
+        heap.push(3);
+
+        assert_eq!(Some(&1), heap.peek());
+        assert_eq!(3, heap.len());
+    }
+
+    #[test]
+    #[should_panic(expected = "lower than 2")]
+    fn should_panic_if_arity_is_too_low() {
+        let _ = DaryHeap::<i32>::with_arity(1);
+    }
+}
@@ -0,0 +1,167 @@
+/// # Description
+/// `FenwickTree`(binary indexed tree) keeps prefix sums of a list which can be changed. It's a lighter alternative to `SegmentTree`:
+/// it takes `n` items instead of `2 * n` and it's simpler, but it only answers sums(anything which can be "subtracted"), not minimums.
+///
+/// Realisation details:
+/// 1. Indexes inside the tree start from `1`. An item at index `i` keeps a sum of `lowbit(i)` list items ending at `i`,
+///    where `lowbit(i)` is the lowest set bit of `i`(`i & i.wrapping_neg()`), e.g. `12`(`0b1100`) keeps a sum of 4 items `9..=12`.
+/// 2. To get a prefix sum, we take the item at `i`, then jump to `i - lowbit(i)` and so on till `0`, every jump removes one bit, so there are `O(log n)` jumps.
+/// 3. To update a list item, we add a delta to every tree item which covers it: `i`, `i + lowbit(i)` and so on till the end.
+///
+/// # Complexity
+/// Build is `O(n)`, `update`, `prefix_sum` and `range_sum` are `O(log n)`.
+#[derive(Debug)]
+pub struct FenwickTree {
+    // `0` index is not used, so indexes of the tree are list indexes + 1
+    tree: Vec<i32>,
+}
+
+impl FenwickTree {
+    /// Creates a tree for a list of `len` zeros.
+    #[must_use]
+    pub fn new(len: usize) -> Self {
+        Self {
+            tree: vec![0; len + 1],
+        }
+    }
+
+    /// Creates a tree from a list. Every item is pushed to its direct "parent" once, instead of `len` updates, so it's `O(n)`.
+    #[must_use]
+    pub fn from(values: &[i32]) -> Self {
+        let mut tree = vec![0; values.len() + 1];
+
+        tree[1..].copy_from_slice(values);
+
+        for index in 1..tree.len() {
+            let parent = index + lowbit(index);
+
+            if parent < tree.len() {
+                tree[parent] += tree[index];
+            }
+        }
+
+        Self { tree }
+    }
+
+    /// Adds `delta` to a list item at `index`(starting from `0`).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of the list bounds.
+    pub fn update(&mut self, index: usize, delta: i32) {
+        assert!(index < self.len(), "Index {index} is out of bounds");
+
+        let mut index = index + 1;
+
+        while index < self.tree.len() {
+            self.tree[index] += delta;
+            index += lowbit(index);
+        }
+    }
+
+    /// Returns a sum of list items from `0` to `index`, both ends included.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of the list bounds.
+    #[must_use]
+    pub fn prefix_sum(&self, index: usize) -> i32 {
+        assert!(index < self.len(), "Index {index} is out of bounds");
+
+        let mut index = index + 1;
+        let mut sum = 0;
+
+        while index > 0 {
+            sum += self.tree[index];
+            index -= lowbit(index);
+        }
+
+        sum
+    }
+
+    /// Returns a sum of list items from `left` to `right`, both ends included. It's a difference of two prefix sums.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `left` is bigger than `right` or `right` is out of the list bounds.
+    #[must_use]
+    pub fn range_sum(&self, left: usize, right: usize) -> i32 {
+        assert!(
+            left <= right,
+            "Left border {left} is bigger than right {right}"
+        );
+
+        let before_left = if left == 0 {
+            0
+        } else {
+            self.prefix_sum(left - 1)
+        };
+
+        self.prefix_sum(right) - before_left
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.tree.len() - 1
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+fn lowbit(index: usize) -> usize {
+    index & index.wrapping_neg()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FenwickTree;
+
+    #[test]
+    fn should_match_brute_force_sums_after_updates() {
+        // given
+        let mut values = vec![3, -1, 4, 1, 5, -9, 2, 6, 5, 3, 5];
+        let mut tree = FenwickTree::from(&values);
+
+        // when
+        for (index, delta) in [(0, 2), (5, 10), (10, -7), (4, 0), (7, -3)] {
+            tree.update(index, delta);
+            values[index] += delta;
+        }
+
+        // then
+        for left in 0..values.len() {
+            assert_eq!(values[..=left].iter().sum::<i32>(), tree.prefix_sum(left));
+
+            for right in left..values.len() {
+                assert_eq!(
+                    values[left..=right].iter().sum::<i32>(),
+                    tree.range_sum(left, right)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn should_build_the_same_tree_as_updates() {
+        let values = [7, 2, 0, 4, 4, 8, 1];
+        let mut tree = FenwickTree::new(values.len());
+
+        for (index, value) in values.iter().enumerate() {
+            tree.update(index, *value);
+        }
+
+        assert_eq!(FenwickTree::from(&values).tree, tree.tree);
+        assert_eq!(7, tree.len());
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn should_panic_if_index_is_out_of_bounds() {
+        let tree = FenwickTree::new(3);
+
+        let _ = tree.prefix_sum(3);
+    }
+}
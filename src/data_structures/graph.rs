@@ -1,12 +1,14 @@
 #![allow(clippy::module_name_repetitions)]
 
+use crate::Traversable;
+use std::cell::RefCell;
 use std::collections::HashMap;
 use std::hash::Hash;
 use std::rc::Rc;
 
-///
-/// Graph traits
-///
+//
+// Graph traits
+//
 
 pub trait GraphNode
 where
@@ -17,7 +19,22 @@ where
 
     fn id(&self) -> &Self::Id;
     fn value(&self) -> &Self::Value;
-    fn nodes(&self) -> &Option<Vec<Rc<Self>>>;
+    fn nodes(&self) -> &RefCell<Vec<Rc<Self>>>;
+}
+
+/// Errors which graphs and graph algorithms return instead of panicking.
+#[derive(Debug, PartialEq, Eq)]
+pub enum GraphError<K> {
+    /// An edge `from -> to` has a weight which an algorithm can't work with.
+    UnsupportedWeight { from: K, to: K, weight: i32 },
+    /// A node which an edge should start from doesn't exist.
+    FromNodeNotFound(K),
+    /// A node which an edge should lead to doesn't exist.
+    ToNodeNotFound(K),
+    /// A node with this id already exists in a graph.
+    DuplicateNode(K),
+    /// There is no edge `from -> to`, but both nodes exist.
+    EdgeNotFound { from: K, to: K },
 }
 
 pub trait Graph<Node, Key>
@@ -26,25 +43,32 @@ where
 {
     fn insert(&mut self, node: Rc<Node>);
     fn get(&self, node_id: &Key) -> Option<&Rc<Node>>;
+    fn contains(&self, node_id: &Key) -> bool {
+        self.get(node_id).is_some()
+    }
     fn len(&self) -> usize;
     fn is_empty(&self) -> bool;
 }
 
-///
-/// Basic graph implementation
-///
+//
+// Basic graph implementation
+//
 
 #[derive(Debug)]
 pub struct BasicGraphNode<T, K> {
     id: K,
     value: T,
-    nodes: Option<Vec<Rc<BasicGraphNode<T, K>>>>,
+    nodes: RefCell<Vec<Rc<BasicGraphNode<T, K>>>>,
 }
 
 impl<T, K> BasicGraphNode<T, K> {
     #[must_use]
     pub fn new(id: K, value: T, nodes: Option<Vec<Rc<BasicGraphNode<T, K>>>>) -> Self {
-        Self { id, value, nodes }
+        Self {
+            id,
+            value,
+            nodes: RefCell::new(nodes.unwrap_or_default()),
+        }
     }
 }
 
@@ -55,20 +79,23 @@ where
     type Id = K;
     type Value = T;
 
-    #[must_use]
     fn id(&self) -> &Self::Id {
         &self.id
     }
-    #[must_use]
     fn value(&self) -> &Self::Value {
         &self.value
     }
-    #[must_use]
-    fn nodes(&self) -> &Option<Vec<Rc<Self>>> {
+    fn nodes(&self) -> &RefCell<Vec<Rc<Self>>> {
         &self.nodes
     }
 }
 
+impl<T, K> Traversable for BasicGraphNode<T, K> {
+    fn children(&self) -> Vec<Rc<Self>> {
+        self.nodes.borrow().clone()
+    }
+}
+
 pub struct BasicGraph<T, K = i32>(HashMap<K, Rc<BasicGraphNode<T, K>>>);
 
 impl<T, K> BasicGraph<T, K> {
@@ -76,24 +103,103 @@ impl<T, K> BasicGraph<T, K> {
     pub fn new() -> Self {
         BasicGraph(HashMap::new())
     }
+
+    /// Creates an empty graph with space for at least `capacity` nodes, so there is no rehashing till the graph grows bigger.
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        BasicGraph(HashMap::with_capacity(capacity))
+    }
+
+    /// Returns a number of nodes the graph can hold without reallocating.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.0.capacity()
+    }
+
+    /// Iterates over all nodes of a graph. Nodes are stored in a `HashMap`, so the order is not specified.
+    pub fn nodes(&self) -> impl Iterator<Item = &Rc<BasicGraphNode<T, K>>> {
+        self.0.values()
+    }
+}
+
+impl<T, K> BasicGraph<T, K>
+where
+    K: Eq + Hash + Clone,
+{
+    /// Creates a graph from an adjacency list: all `nodes` are created first, and then `edges` are connected in the given order.
+    /// So there is no need to create `Rc`s of nodes manually and to think in which order nodes should be created.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any edge points from or to a node which is not in `nodes`.
+    #[must_use]
+    pub fn from_adjacency(nodes: Vec<(K, T)>, edges: &[(K, K)]) -> Self {
+        let mut graph = BasicGraph::new();
+
+        for (id, value) in nodes {
+            graph.insert(Rc::new(BasicGraphNode::new(id, value, None)));
+        }
+
+        for (from_node_id, to_node_id) in edges {
+            graph.connect(from_node_id.clone(), to_node_id.clone());
+        }
+
+        graph
+    }
+
+    ///
+    /// # Panics
+    ///
+    /// If `from_node_id` or `to_node_id` does not exist in a `BasicGraph`, then this method will panic at either of them.
+    pub fn connect(&mut self, from_node_id: K, to_node_id: K) {
+        let from_node = self
+            .get(&from_node_id)
+            .expect("Passed \"from_node_id\" does not exist");
+        let to_node = self
+            .get(&to_node_id)
+            .expect("Passed \"to_node_id\" does not exist");
+
+        from_node.nodes.borrow_mut().push(Rc::clone(to_node));
+    }
+
+    /// Unlike `insert`, which replaces an existing node with the same id(and leaves edges pointing to the old node), this method keeps the existing node.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GraphError::DuplicateNode` if a node with the same id already exists, the graph stays unchanged in this case.
+    pub fn try_insert(&mut self, node: Rc<BasicGraphNode<T, K>>) -> Result<(), GraphError<K>> {
+        if self.contains(&node.id) {
+            return Err(GraphError::DuplicateNode(node.id.clone()));
+        }
+
+        self.insert(node);
+
+        Ok(())
+    }
+
+    /// Returns a number of outgoing edges of a node or `None` if the node does not exist.
+    #[must_use]
+    pub fn out_degree(&self, node_id: &K) -> Option<usize> {
+        Some(self.get(node_id)?.nodes.borrow().len())
+    }
 }
 
 impl<T, K> Graph<BasicGraphNode<T, K>, K> for BasicGraph<T, K>
 where
-    K: Eq + Hash + Copy,
+    K: Eq + Hash + Clone,
 {
     fn insert(&mut self, node: Rc<BasicGraphNode<T, K>>) {
-        self.0.insert(node.id, node);
+        self.0.insert(node.id.clone(), node);
     }
-    #[must_use]
     fn get(&self, node_id: &K) -> Option<&Rc<BasicGraphNode<T, K>>> {
         self.0.get(node_id)
     }
-    #[must_use]
+    fn contains(&self, node_id: &K) -> bool {
+        self.0.contains_key(node_id)
+    }
     fn len(&self) -> usize {
         self.0.len()
     }
-    #[must_use]
     fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
@@ -101,9 +207,107 @@ where
 
 impl<T, K> Default for BasicGraph<T, K>
 where
-    K: Eq + Hash + Copy,
+    K: Eq + Hash + Clone,
 {
     fn default() -> Self {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{BasicGraph, BasicGraphNode, Graph, GraphError, GraphNode};
+    use std::rc::Rc;
+
+    #[test]
+    fn should_create_graph_from_adjacency() {
+        // given
+        let graph = BasicGraph::from_adjacency(
+            vec![(1, "one"), (2, "two"), (3, "three")],
+            &[(1, 2), (1, 3), (2, 3)],
+        );
+
+        // then
+        assert_eq!(3, graph.len());
+        assert_eq!(&"two", graph.get(&2).unwrap().value());
+        assert_eq!(Some(2), graph.out_degree(&1));
+        assert_eq!(Some(0), graph.out_degree(&3));
+        assert_eq!(
+            vec![2, 3],
+            graph
+                .get(&1)
+                .unwrap()
+                .nodes()
+                .borrow()
+                .iter()
+                .map(|node| *node.id())
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "\"to_node_id\" does not exist")]
+    fn should_panic_if_adjacency_edge_points_to_unknown_node() {
+        let _ = BasicGraph::from_adjacency(vec![(1, ())], &[(1, 2)]);
+    }
+
+    #[test]
+    fn should_behave_the_same_with_capacity() {
+        // given
+        let mut graph = BasicGraph::with_capacity(100);
+
+        // when
+        for id in 1..=100 {
+            graph.insert(Rc::new(BasicGraphNode::new(id, (), None)));
+        }
+
+        graph.connect(1, 2);
+
+        // then
+        assert!(graph.capacity() >= 100);
+        assert_eq!(100, graph.len());
+        assert_eq!(Some(1), graph.out_degree(&1));
+        assert!(BasicGraph::<(), i32>::with_capacity(10).is_empty());
+    }
+
+    #[test]
+    fn should_not_insert_duplicate() {
+        // given
+        let mut graph = BasicGraph::new();
+
+        assert_eq!(
+            Ok(()),
+            graph.try_insert(Rc::new(BasicGraphNode::new(1, "original", None)))
+        );
+
+        // when
+        let result = graph.try_insert(Rc::new(BasicGraphNode::new(1, "duplicate", None)));
+
+        // then
+        assert_eq!(Err(GraphError::DuplicateNode(1)), result);
+        assert_eq!(1, graph.len());
+        assert!(graph.contains(&1));
+        assert!(!graph.contains(&2));
+        assert_eq!(&"original", graph.get(&1).unwrap().value());
+    }
+
+    #[test]
+    fn should_return_out_degree() {
+        // given
+        let mut graph = BasicGraph::new();
+
+        for id in 1..=4 {
+            graph.insert(Rc::new(BasicGraphNode::new(id, (), None)));
+        }
+
+        graph.connect(1, 2);
+        graph.connect(1, 3);
+        graph.connect(1, 4);
+        graph.connect(2, 1);
+
+        // then
+        assert_eq!(Some(3), graph.out_degree(&1));
+        assert_eq!(Some(0), graph.out_degree(&4));
+        assert_eq!(None, graph.out_degree(&5));
+    }
+}
@@ -0,0 +1,250 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// # Description
+/// `IndexedMinHeap` is a priority queue where the item with the lowest priority is always on top.
+///
+/// It's a binary heap stored in a vector: children of an item at index `i` are at indexes `2i + 1` and `2i + 2`,
+/// and every item has a priority lower than or equal to priorities of its children.
+///
+/// The difference from `std::collections::BinaryHeap` is that every key is "indexed", i.e. we keep a position of every key in the vector.
+/// Knowing a position, we can change a priority of an item already in the heap(`decrease_key`) and move it up to a right place in `O(log n)`.
+/// Without positions we'd have to search for the item first, which is `O(n)`, or push duplicates and skip outdated ones later.
+/// That's exactly what Dijkstra search(and Prim's algorithm) needs, when a cheaper path to an already queued node is found.
+///
+/// # Complexity
+/// * `push`, `pop`, `decrease_key` - `O(log n)`
+/// * `peek`, `contains`, `priority` - `O(1)`
+pub struct IndexedMinHeap<K, P = i32> {
+    heap: Vec<(K, P)>,
+    positions: HashMap<K, usize>,
+}
+
+impl<K, P> IndexedMinHeap<K, P>
+where
+    K: Hash + Eq + Clone,
+    P: Ord,
+{
+    #[must_use]
+    pub fn new() -> Self {
+        IndexedMinHeap {
+            heap: vec![],
+            positions: HashMap::new(),
+        }
+    }
+
+    /// Adds a new key with a priority. If the key is already in the heap, then its priority is replaced.
+    pub fn push(&mut self, key: K, priority: P) {
+        if let Some(&position) = self.positions.get(&key) {
+            let is_lower = priority < self.heap[position].1;
+
+            self.heap[position].1 = priority;
+
+            if is_lower {
+                self.sift_up(position);
+            } else {
+                self.sift_down(position);
+            }
+
+            return;
+        }
+
+        self.positions.insert(key.clone(), self.heap.len());
+        self.heap.push((key, priority));
+        self.sift_up(self.heap.len() - 1);
+    }
+
+    /// Takes a key with the lowest priority out of the heap.
+    pub fn pop(&mut self) -> Option<(K, P)> {
+        if self.heap.is_empty() {
+            return None;
+        }
+
+        let last = self.heap.len() - 1;
+
+        self.swap(0, last);
+
+        let (key, priority) = self.heap.pop()?;
+
+        self.positions.remove(&key);
+        self.sift_down(0);
+
+        Some((key, priority))
+    }
+
+    #[must_use]
+    pub fn peek(&self) -> Option<(&K, &P)> {
+        self.heap.first().map(|(key, priority)| (key, priority))
+    }
+
+    /// Lowers a priority of a key which is already in the heap.
+    /// Returns `false` if the key is not in the heap or the new priority is not lower than the current one, the heap stays unchanged in this case.
+    pub fn decrease_key(&mut self, key: &K, priority: P) -> bool {
+        let Some(&position) = self.positions.get(key) else {
+            return false;
+        };
+
+        if priority >= self.heap[position].1 {
+            return false;
+        }
+
+        self.heap[position].1 = priority;
+        self.sift_up(position);
+
+        true
+    }
+
+    #[must_use]
+    pub fn contains(&self, key: &K) -> bool {
+        self.positions.contains_key(key)
+    }
+
+    #[must_use]
+    pub fn priority(&self, key: &K) -> Option<&P> {
+        self.positions
+            .get(key)
+            .map(|&position| &self.heap[position].1)
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    fn swap(&mut self, first: usize, second: usize) {
+        self.heap.swap(first, second);
+
+        for index in [first, second] {
+            if let Some(position) = self.positions.get_mut(&self.heap[index].0) {
+                *position = index;
+            }
+        }
+    }
+
+    /// Moves an item up while it's lower than its parent
+    fn sift_up(&mut self, mut index: usize) {
+        while index > 0 {
+            let parent = (index - 1) / 2;
+
+            if self.heap[index].1 >= self.heap[parent].1 {
+                break;
+            }
+
+            self.swap(index, parent);
+            index = parent;
+        }
+    }
+
+    /// Moves an item down while any of its children is lower than it, the lowest child takes its place
+    fn sift_down(&mut self, mut index: usize) {
+        loop {
+            let mut lowest = index;
+
+            for child in [2 * index + 1, 2 * index + 2] {
+                if child < self.heap.len() && self.heap[child].1 < self.heap[lowest].1 {
+                    lowest = child;
+                }
+            }
+
+            if lowest == index {
+                break;
+            }
+
+            self.swap(index, lowest);
+            index = lowest;
+        }
+    }
+}
+
+impl<K, P> Default for IndexedMinHeap<K, P>
+where
+    K: Hash + Eq + Clone,
+    P: Ord,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IndexedMinHeap;
+
+    #[test]
+    fn should_pop_in_priority_order() {
+        // given
+        let mut heap = IndexedMinHeap::new();
+
+        for (key, priority) in [("e", 50), ("b", 20), ("d", 40), ("a", 10), ("c", 30)] {
+            heap.push(key, priority);
+        }
+
+        // then
+        assert_eq!(5, heap.len());
+        assert_eq!(Some((&"a", &10)), heap.peek());
+
+        let mut popped = vec![];
+
+        while let Some((key, _)) = heap.pop() {
+            popped.push(key);
+        }
+
+        assert_eq!(vec!["a", "b", "c", "d", "e"], popped);
+        assert!(heap.is_empty());
+    }
+
+    #[test]
+    fn should_decrease_key() {
+        // given
+        let mut heap = IndexedMinHeap::new();
+
+        heap.push("a", 10);
+        heap.push("b", 20);
+        heap.push("c", 30);
+
+        // when
+        assert!(heap.decrease_key(&"c", 5));
+
+        // then
+        assert_eq!(Some(&5), heap.priority(&"c"));
+        assert_eq!(Some(("c", 5)), heap.pop());
+        assert_eq!(Some(("a", 10)), heap.pop());
+        assert_eq!(Some(("b", 20)), heap.pop());
+    }
+
+    #[test]
+    fn should_not_decrease_key_to_bigger_priority_or_missing_key() {
+        // given
+        let mut heap = IndexedMinHeap::new();
+
+        heap.push("a", 10);
+
+        // then
+        assert!(!heap.decrease_key(&"a", 15));
+        assert!(!heap.decrease_key(&"b", 1));
+        assert_eq!(Some(&10), heap.priority(&"a"));
+        assert!(!heap.contains(&"b"));
+    }
+
+    #[test]
+    fn should_replace_priority_on_push_of_existing_key() {
+        // given
+        let mut heap = IndexedMinHeap::new();
+
+        heap.push("a", 10);
+        heap.push("b", 20);
+
+        // when
+        heap.push("a", 30);
+
+        // then
+        assert_eq!(2, heap.len());
+        assert_eq!(Some(("b", 20)), heap.pop());
+        assert_eq!(Some(("a", 30)), heap.pop());
+    }
+}
@@ -0,0 +1,253 @@
+#![allow(clippy::module_name_repetitions)]
+
+use crate::binary_search_tree::{AVLTree, BinarySearchTreeNode};
+use std::cell::Cell;
+use std::cmp::Ordering;
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::rc::Rc;
+
+/// A closed interval `[low, high]`.
+/// Intervals are compared by `low` first and by `high` after that, so in a tree they are sorted(keyed) by `low`.
+#[derive(Debug)]
+pub struct Interval {
+    low: i32,
+    high: i32,
+    // The biggest `high` in a subtree where a node with this interval is the head, it's updated by `IntervalTree` after every insert
+    max_high: Cell<i32>,
+}
+
+impl Interval {
+    #[must_use]
+    pub fn low(&self) -> i32 {
+        self.low
+    }
+
+    #[must_use]
+    pub fn high(&self) -> i32 {
+        self.high
+    }
+
+    fn overlaps(&self, (low, high): (i32, i32)) -> bool {
+        self.low <= high && low <= self.high
+    }
+}
+
+impl PartialEq for Interval {
+    fn eq(&self, other: &Self) -> bool {
+        (self.low, self.high) == (other.low, other.high)
+    }
+}
+
+impl Eq for Interval {}
+
+impl PartialOrd for Interval {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Interval {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.low, self.high).cmp(&(other.low, other.high))
+    }
+}
+
+/// # Description
+/// `IntervalTree` keeps intervals in an `AVLTree` and helps to find all intervals which overlap with a given one.
+/// To do it fast, every node additionally knows the biggest `high` in its subtree, so we can skip subtrees which end before a query starts.
+///
+/// Realisation details:
+/// 1. Intervals are values of an `AVLTree`, so insert and balancing(rotations) are done by the `AVLTree`.
+/// 2. After an insert only nodes on the way from the new node to the head can get a different subtree(rotations happen only on this way),
+///    so we update `max_high` of these nodes and their children going from the bottom to the top.
+/// 3. To find overlapping intervals, we go to the left subtree only if its `max_high` is not lower than a query `low`,
+///    and to the right subtree only if a current `low` is not bigger than a query `high`(all lows on the right are bigger or equal).
+///
+/// # Complexity
+/// Insert is `O(log n)`. Search is `O(log n + m)`, where `m` is a number of found intervals.
+pub struct IntervalTree<K = i32> {
+    tree: Option<AVLTree<Interval, K>>,
+}
+
+impl<K> IntervalTree<K>
+where
+    K: Eq + Hash + Clone + Debug,
+{
+    #[must_use]
+    pub fn new() -> Self {
+        Self { tree: None }
+    }
+
+    ///
+    /// # Panics
+    ///
+    /// Panics if `low` is bigger than `high`.
+    pub fn insert(&mut self, id: K, low: i32, high: i32) {
+        assert!(low <= high, "Interval low can't be bigger than high");
+
+        let interval = Interval {
+            low,
+            high,
+            max_high: Cell::new(high),
+        };
+
+        let Some(tree) = self.tree.as_mut() else {
+            self.tree = Some(AVLTree::from_head(id, interval));
+            return;
+        };
+
+        let mut next_node = Some(Rc::clone(tree.insert_and_get(id, interval)));
+
+        while let Some(node) = next_node {
+            for child in node.nodes().iter().flatten() {
+                IntervalTree::update_max_high(child);
+            }
+
+            IntervalTree::update_max_high(&node);
+            next_node = node.parent().upgrade();
+        }
+    }
+
+    /// Returns all intervals which have at least one common point with `query`(`query` is `(low, high)` and both ends are included).
+    #[must_use]
+    pub fn overlapping(&self, query: (i32, i32)) -> Vec<&Interval> {
+        let Some(tree) = self.tree.as_ref() else {
+            return vec![];
+        };
+
+        let mut found_ids = vec![];
+        let mut stack = vec![Rc::clone(tree.head())];
+
+        while let Some(node) = stack.pop() {
+            let interval = node.value();
+
+            if interval.overlaps(query) {
+                found_ids.push(node.id().clone());
+            }
+
+            let [left, right] = &*node.nodes();
+
+            if let Some(left) = left {
+                if left.value().max_high.get() >= query.0 {
+                    stack.push(Rc::clone(left));
+                }
+            }
+
+            if let Some(right) = right {
+                if interval.low <= query.1 && right.value().max_high.get() >= query.0 {
+                    stack.push(Rc::clone(right));
+                }
+            }
+        }
+
+        // Nodes we've traversed are local clones, so we take intervals from the tree to return references which live as long as the tree
+        found_ids
+            .iter()
+            .filter_map(|id| tree.get(id))
+            .map(|node| node.value())
+            .collect()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.tree.as_ref().map_or(0, AVLTree::len)
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.tree.is_none()
+    }
+
+    fn update_max_high(node: &Rc<BinarySearchTreeNode<Interval, K>>) {
+        let max_high = node
+            .nodes()
+            .iter()
+            .flatten()
+            .map(|child| child.value().max_high.get())
+            .fold(node.value().high, i32::max);
+
+        node.value().max_high.set(max_high);
+    }
+}
+
+impl<K> Default for IntervalTree<K>
+where
+    K: Eq + Hash + Clone + Debug,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IntervalTree;
+
+    #[test]
+    fn should_find_overlapping_intervals() {
+        // given
+        let mut tree = IntervalTree::new();
+        let intervals = [
+            (15, 20),
+            (10, 30),
+            (17, 19),
+            (5, 20),
+            (12, 15),
+            (30, 40),
+            (1, 3),
+            (45, 50),
+        ];
+
+        for (id, (low, high)) in intervals.iter().enumerate() {
+            tree.insert(id, *low, *high);
+        }
+
+        // when
+        let mut found: Vec<_> = tree
+            .overlapping((18, 25))
+            .iter()
+            .map(|interval| (interval.low(), interval.high()))
+            .collect();
+        found.sort_unstable();
+
+        // then
+        assert_eq!(8, tree.len());
+        assert_eq!(vec![(5, 20), (10, 30), (15, 20), (17, 19)], found);
+        assert!(tree.overlapping((41, 44)).is_empty());
+        assert_eq!(1, tree.overlapping((3, 4)).len());
+    }
+
+    #[test]
+    fn should_match_brute_force_search() {
+        let mut tree = IntervalTree::new();
+        let intervals: Vec<_> = (0..60)
+            .map(|id| {
+                let low = (id * 37) % 100;
+                (low, low + (id * 13) % 20)
+            })
+            .collect();
+
+        for (id, (low, high)) in intervals.iter().enumerate() {
+            tree.insert(id, *low, *high);
+        }
+
+        for query in [(0, 0), (10, 12), (50, 70), (99, 130), (120, 130)] {
+            let mut found: Vec<_> = tree
+                .overlapping(query)
+                .iter()
+                .map(|interval| (interval.low(), interval.high()))
+                .collect();
+            let mut expected: Vec<_> = intervals
+                .iter()
+                .copied()
+                .filter(|(low, high)| *low <= query.1 && query.0 <= *high)
+                .collect();
+
+            found.sort_unstable();
+            expected.sort_unstable();
+
+            assert_eq!(expected, found);
+        }
+    }
+}
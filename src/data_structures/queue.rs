@@ -1,5 +1,5 @@
 use std::cell::RefCell;
-use std::fmt::Debug;
+use std::fmt::{self, Debug, Formatter};
 use std::rc::Rc;
 
 type Link<'t, T> = Option<Rc<RefCell<Node<'t, T>>>>;
@@ -36,8 +36,15 @@ where
 {
     head: Link<'t, T>,
     tail: Link<'t, T>,
+    len: usize,
+    // Only `try_add` respects it, `add` always adds an item
+    max: Option<usize>,
 }
 
+/// An error which `try_add` returns when a bounded queue already has the maximum number of items.
+#[derive(Debug, PartialEq, Eq)]
+pub struct QueueFull;
+
 impl<'t, T> Queue<'t, T>
 where
     T: Debug,
@@ -47,6 +54,17 @@ where
         Queue {
             head: None,
             tail: None,
+            len: 0,
+            max: None,
+        }
+    }
+
+    /// Creates a bounded queue, `try_add` rejects new items when there are already `max` items in the queue(back-pressure for work queues).
+    #[must_use]
+    pub fn with_max(max: usize) -> Self {
+        Queue {
+            max: Some(max),
+            ..Queue::new()
         }
     }
 
@@ -59,7 +77,10 @@ where
         queue
     }
 
+    /// Adds an item to the end of the queue. It ignores the maximum of a bounded queue, use `try_add` to respect it.
     pub fn add(&mut self, value: &'t T) {
+        self.len += 1;
+
         let item = Rc::new(RefCell::new(Node {
             value: Some(value),
             next: None,
@@ -77,8 +98,30 @@ where
         }
     }
 
+    ///
+    /// # Errors
+    ///
+    /// Returns `QueueFull` if the queue is bounded and already has the maximum number of items, the item is not added in this case.
+    pub fn try_add(&mut self, value: &'t T) -> Result<(), QueueFull> {
+        if self.max.is_some_and(|max| self.len >= max) {
+            return Err(QueueFull);
+        }
+
+        self.add(value);
+
+        Ok(())
+    }
+
     pub fn append(&mut self, slice: &'t [T]) {
-        for value in slice {
+        self.extend(slice);
+    }
+
+    /// Adds all items of `iter` in the order the iterator gives them, so any iterable of references can be added without collecting it into a slice first.
+    pub fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = &'t T>,
+    {
+        for value in iter {
             self.add(value);
         }
     }
@@ -93,12 +136,68 @@ where
             }
 
             self.head = next;
+            self.len -= 1;
 
             return value;
         }
 
         None
     }
+
+    /// Removes all items for which `predicate` returns `false`, e.g. to cancel queued items. The order of kept items doesn't change.
+    ///
+    /// # Complexity
+    /// `O(n)`, we walk through the list once and link a previous kept node to the next node after every removed one.
+    pub fn retain<F>(&mut self, predicate: F)
+    where
+        F: Fn(&T) -> bool,
+    {
+        let is_kept = |node: &Rc<RefCell<Node<'t, T>>>| node.borrow().value.is_some_and(&predicate);
+
+        // Removing from the beginning is the same as taking, it also keeps `tail` correct if all items are removed
+        while let Some(head) = self.head.clone() {
+            if is_kept(&head) {
+                break;
+            }
+
+            self.take();
+        }
+
+        let Some(mut previous) = self.head.clone() else {
+            return;
+        };
+
+        loop {
+            let Some(next) = previous.borrow().next.clone() else {
+                break;
+            };
+
+            if is_kept(&next) {
+                previous = next;
+                continue;
+            }
+
+            let after_next = next.borrow_mut().next.take();
+
+            // The removed node was the last one, so the previous node becomes the last one
+            if after_next.is_none() {
+                self.tail = Some(Rc::clone(&previous));
+            }
+
+            previous.borrow_mut().next = after_next;
+            self.len -= 1;
+        }
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
 }
 
 impl<'t, T> Default for Queue<'t, T>
@@ -110,9 +209,33 @@ where
     }
 }
 
+/// Prints remaining items in the order they are going to be taken, e.g. `[1, 15, 20]`. The queue stays unchanged.
+impl<'t, T> Debug for Queue<'t, T>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut list = f.debug_list();
+        let mut next = self.head.clone();
+
+        while let Some(node) = next {
+            let node = node.borrow();
+
+            if let Some(value) = node.value {
+                list.entry(value);
+            }
+
+            next = node.next.clone();
+        }
+
+        list.finish()
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::Queue;
+    use super::{Queue, QueueFull};
+    use std::collections::HashSet;
 
     #[test]
     fn should_add_and_take_from_queue() {
@@ -136,4 +259,110 @@ mod tests {
 
         assert_eq!(None, queue.take());
     }
+
+    #[test]
+    fn should_extend_queue_from_iterator() {
+        // given
+        let set = HashSet::from([2, 4, 6, 8]);
+        let mut queue = Queue::from(&[1]);
+
+        // when
+        queue.extend(set.iter().filter(|&&value| value > 2));
+
+        // then
+        let mut taken = vec![];
+
+        assert_eq!(Some(&1), queue.take());
+
+        while let Some(&value) = queue.take() {
+            taken.push(value);
+        }
+
+        taken.sort_unstable();
+
+        assert_eq!(vec![4, 6, 8], taken);
+    }
+
+    #[test]
+    fn should_reject_items_over_max() {
+        // given
+        let values = [1, 2, 3, 4];
+        let mut queue = Queue::with_max(3);
+
+        // when
+        for value in &values[..3] {
+            assert_eq!(Ok(()), queue.try_add(value));
+        }
+
+        // then
+        assert_eq!(3, queue.len());
+        assert_eq!(Err(QueueFull), queue.try_add(&values[3]));
+        assert_eq!(3, queue.len());
+
+        assert_eq!(Some(&1), queue.take());
+        assert_eq!(Ok(()), queue.try_add(&values[3]));
+        assert_eq!("[2, 3, 4]", format!("{queue:?}"));
+
+        // `add` ignores the maximum
+        queue.add(&values[0]);
+
+        assert_eq!(4, queue.len());
+    }
+
+    #[test]
+    fn should_retain_matching_items() {
+        // given
+        let values: Vec<i32> = (1..=10).collect();
+        let mut queue = Queue::from(&values);
+
+        // when
+        queue.retain(|value| value % 2 == 1);
+
+        // then
+        assert_eq!(5, queue.len());
+        assert_eq!("[1, 3, 5, 7, 9]", format!("{queue:?}"));
+
+        // The last node was removed, so adding must go after the new tail
+        queue.add(&values[0]);
+
+        let mut taken = vec![];
+
+        while let Some(&value) = queue.take() {
+            taken.push(value);
+        }
+
+        assert_eq!(vec![1, 3, 5, 7, 9, 1], taken);
+    }
+
+    #[test]
+    fn should_retain_nothing_and_keep_queue_usable() {
+        let values = [2, 4, 6];
+        let mut queue = Queue::from(&values);
+
+        queue.retain(|value| value % 2 == 1);
+
+        assert!(queue.is_empty());
+        assert_eq!(None, queue.take());
+
+        queue.add(&values[0]);
+
+        assert_eq!(Some(&2), queue.take());
+    }
+
+    #[test]
+    fn should_format_remaining_items_in_order() {
+        // given
+        let mut queue = Queue::from(&[1, 15, 20]);
+
+        queue.add(&43);
+
+        // then
+        assert_eq!("[1, 15, 20, 43]", format!("{queue:?}"));
+
+        queue.take();
+
+        assert_eq!("[15, 20, 43]", format!("{queue:?}"));
+        assert_eq!(Some(&15), queue.take());
+        assert_eq!("[]", format!("{:?}", Queue::<i32>::new()));
+    }
 }
@@ -0,0 +1,184 @@
+/// # Description
+/// `RingBuffer` is a queue with a fixed capacity, e.g. for keeping the last `n` events.
+/// It's backed by a vector of `capacity` slots, which is allocated once, and two positions: `head`(the oldest item) and `len`.
+/// The next item goes to `(head + len) % capacity`, so when the end of the vector is reached, we continue from its beginning.
+///
+/// When the buffer is full, `push` either overwrites the oldest item(the default mode, see `new`), or returns an error(the strict mode, see `strict`).
+///
+/// # Complexity
+/// `push` and `pop` are `O(1)`, nothing is ever moved inside the vector.
+#[derive(Debug)]
+pub struct RingBuffer<T> {
+    // Empty slots are `None`, so we don't need `T: Default` or `unsafe` to keep uninitialized items
+    items: Vec<Option<T>>,
+    head: usize,
+    len: usize,
+    is_strict: bool,
+}
+
+/// An error which `push` of a strict `RingBuffer` returns when the buffer is full. It gives the rejected item back.
+#[derive(Debug, PartialEq, Eq)]
+pub struct RingBufferFull<T>(pub T);
+
+impl<T> RingBuffer<T> {
+    /// Creates a buffer which overwrites the oldest item when a new item is pushed into the full buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is `0`.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "Capacity of a ring buffer can't be 0");
+
+        RingBuffer {
+            items: (0..capacity).map(|_| None).collect(),
+            head: 0,
+            len: 0,
+            is_strict: false,
+        }
+    }
+
+    /// Creates a buffer which rejects new items when it's full.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is `0`.
+    #[must_use]
+    pub fn strict(capacity: usize) -> Self {
+        RingBuffer {
+            is_strict: true,
+            ..RingBuffer::new(capacity)
+        }
+    }
+
+    /// Adds an item after the newest one. If the buffer is full, then in the default mode the oldest item is dropped to make room.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RingBufferFull` with the item if the buffer is strict and full, the buffer stays unchanged in this case.
+    pub fn push(&mut self, item: T) -> Result<(), RingBufferFull<T>> {
+        if self.is_full() {
+            if self.is_strict {
+                return Err(RingBufferFull(item));
+            }
+
+            // The oldest slot becomes the newest one
+            self.items[self.head] = Some(item);
+            self.head = (self.head + 1) % self.capacity();
+
+            return Ok(());
+        }
+
+        let tail = (self.head + self.len) % self.capacity();
+
+        self.items[tail] = Some(item);
+        self.len += 1;
+
+        Ok(())
+    }
+
+    /// Takes the oldest item out of the buffer.
+    pub fn pop(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let item = self.items[self.head].take();
+
+        self.head = (self.head + 1) % self.capacity();
+        self.len -= 1;
+
+        item
+    }
+
+    /// Iterates over items from the oldest to the newest.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        (0..self.len)
+            .filter_map(|offset| self.items[(self.head + offset) % self.capacity()].as_ref())
+    }
+
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.items.len()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[must_use]
+    pub fn is_full(&self) -> bool {
+        self.len == self.capacity()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RingBuffer, RingBufferFull};
+
+    #[test]
+    fn should_overwrite_oldest_items_when_full() {
+        // given
+        let mut buffer = RingBuffer::new(3);
+
+        // when
+        for item in 1..=5 {
+            assert_eq!(Ok(()), buffer.push(item));
+        }
+
+        // then
+        assert!(buffer.is_full());
+        assert_eq!(vec![&3, &4, &5], buffer.iter().collect::<Vec<_>>());
+        assert_eq!(Some(3), buffer.pop());
+        assert_eq!(Ok(()), buffer.push(6));
+        assert_eq!(vec![&4, &5, &6], buffer.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn should_reject_items_in_strict_mode_when_full() {
+        // given
+        let mut buffer = RingBuffer::strict(3);
+
+        // when
+        for item in 1..=3 {
+            assert_eq!(Ok(()), buffer.push(item));
+        }
+
+        // then
+        assert_eq!(Err(RingBufferFull(4)), buffer.push(4));
+        assert_eq!(vec![&1, &2, &3], buffer.iter().collect::<Vec<_>>());
+
+        assert_eq!(Some(1), buffer.pop());
+        assert_eq!(Ok(()), buffer.push(4));
+        assert_eq!(vec![&2, &3, &4], buffer.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn should_pop_in_insertion_order_across_the_end() {
+        let mut buffer = RingBuffer::new(2);
+
+        assert_eq!(None, buffer.pop());
+
+        for item in ["a", "b", "c"] {
+            buffer.push(item).unwrap();
+            assert!(buffer.len() <= 2);
+        }
+
+        assert_eq!(Some("b"), buffer.pop());
+        assert_eq!(Some("c"), buffer.pop());
+        assert_eq!(None, buffer.pop());
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "can't be 0")]
+    fn should_panic_if_capacity_is_zero() {
+        let _ = RingBuffer::<i32>::new(0);
+    }
+}
@@ -0,0 +1,185 @@
+#![allow(clippy::module_name_repetitions)]
+
+use std::ops::Range;
+
+/// What a `SegmentTree` calculates for a range.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Aggregate {
+    Sum,
+    Min,
+}
+
+impl Aggregate {
+    fn combine(self, left: i32, right: i32) -> i32 {
+        match self {
+            Aggregate::Sum => left + right,
+            Aggregate::Min => left.min(right),
+        }
+    }
+
+    /// A value which doesn't change a result when combined with it, it's a result for an empty range.
+    fn identity(self) -> i32 {
+        match self {
+            Aggregate::Sum => 0,
+            Aggregate::Min => i32::MAX,
+        }
+    }
+}
+
+/// # Description
+/// `SegmentTree` answers "what is a sum(or a minimum) of items in a range" questions and allows to change items between the questions.
+/// Summing a range directly is `O(n)`, and prefix sums can't be updated cheaply, whereas a segment tree does both in `O(log n)`.
+///
+/// Realisation details:
+/// 1. The tree is stored in a vector of `2 * n` items without pointers. Items of the list are leaves and are stored at `n..2 * n` indexes.
+/// 2. Every parent at index `i` keeps an aggregate of its children at `2 * i` and `2 * i + 1`, so `1` index is an aggregate of the whole list(`0` index is not used).
+/// 3. To update an item, we change its leaf and recalculate all parents on the way up to the top.
+/// 4. To query a range, we move both borders up level by level. If a border is a right child(for the left border) or a left child(for the right border),
+///    then its parent covers items outside the range, so we take the node itself and move the border inwards.
+///
+/// # Complexity
+/// Build is `O(n)`, update and query are `O(log n)`.
+pub struct SegmentTree {
+    len: usize,
+    aggregate: Aggregate,
+    tree: Vec<i32>,
+}
+
+impl SegmentTree {
+    #[must_use]
+    pub fn build(values: &[i32], aggregate: Aggregate) -> Self {
+        let len = values.len();
+        let mut tree = vec![aggregate.identity(); 2 * len];
+
+        tree[len..].copy_from_slice(values);
+
+        for index in (1..len).rev() {
+            tree[index] = aggregate.combine(tree[2 * index], tree[2 * index + 1]);
+        }
+
+        Self {
+            len,
+            aggregate,
+            tree,
+        }
+    }
+
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of the list bounds.
+    pub fn update(&mut self, index: usize, value: i32) {
+        assert!(index < self.len, "Index {index} is out of bounds");
+
+        let mut index = index + self.len;
+
+        self.tree[index] = value;
+
+        while index > 1 {
+            index /= 2;
+            self.tree[index] = self
+                .aggregate
+                .combine(self.tree[2 * index], self.tree[2 * index + 1]);
+        }
+    }
+
+    /// Returns an aggregate of items in `range`(the end is excluded, as in any Rust range).
+    /// An empty range returns `0` for `Aggregate::Sum` and `i32::MAX` for `Aggregate::Min`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` ends after the list end.
+    #[must_use]
+    pub fn query(&self, range: Range<usize>) -> i32 {
+        assert!(range.end <= self.len, "Range {range:?} is out of bounds");
+
+        let mut result = self.aggregate.identity();
+        let mut left = range.start + self.len;
+        let mut right = range.end + self.len;
+
+        while left < right {
+            if left % 2 == 1 {
+                result = self.aggregate.combine(result, self.tree[left]);
+                left += 1;
+            }
+
+            if right % 2 == 1 {
+                right -= 1;
+                result = self.aggregate.combine(result, self.tree[right]);
+            }
+
+            left /= 2;
+            right /= 2;
+        }
+
+        result
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Aggregate, SegmentTree};
+
+    #[test]
+    fn should_query_and_update_sums() {
+        // given
+        let mut values = vec![5, 3, 8, 6, 1, 4, 7];
+        let mut tree = SegmentTree::build(&values, Aggregate::Sum);
+
+        // then
+        assert_eq!(34, tree.query(0..7));
+        assert_eq!(17, tree.query(1..4));
+        assert_eq!(8, tree.query(2..3));
+        assert_eq!(0, tree.query(3..3));
+
+        // when
+        tree.update(3, -10);
+        values[3] = -10;
+
+        // then
+        for start in 0..values.len() {
+            for end in start..=values.len() {
+                assert_eq!(
+                    values[start..end].iter().sum::<i32>(),
+                    tree.query(start..end)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn should_query_and_update_minimums() {
+        // given
+        let mut tree = SegmentTree::build(&[5, 3, 8, 6, 1, 4], Aggregate::Min);
+
+        // then
+        assert_eq!(1, tree.query(0..6));
+        assert_eq!(3, tree.query(0..4));
+        assert_eq!(6, tree.query(2..4));
+
+        // when
+        tree.update(1, 9);
+
+        // then
+        assert_eq!(5, tree.query(0..4));
+        assert_eq!(i32::MAX, tree.query(2..2));
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn should_panic_if_range_is_out_of_bounds() {
+        let tree = SegmentTree::build(&[1, 2], Aggregate::Sum);
+
+        let _ = tree.query(0..3);
+    }
+}
@@ -0,0 +1,241 @@
+use std::iter;
+use std::mem;
+
+const MAX_LEVEL: usize = 32;
+const HEAD: usize = 0;
+
+struct SkipListNode<T> {
+    // `None` for the head, which is lower than any value, and for removed nodes waiting for reuse
+    value: Option<T>,
+    // A next node on every level of this node, level `0` links all nodes
+    next: Vec<Option<usize>>,
+}
+
+/// # Description
+/// `SkipList` is a sorted set built from several linked lists on top of each other. The lowest level links all values in order,
+/// and every next level links only about a half of nodes of the level below, so upper levels are "express lanes" over lower ones.
+///
+/// Realisation details:
+/// 1. To find a value, we start from the top level of the head and go right while the next value is lower, then go one level down and repeat.
+///    Every level skips about a half of the rest, so it's `O(log n)` steps, similar to binary search.
+/// 2. A level of a new node is random: it gets level `k` with probability `1 / 2^k`, so there is no balancing(and no rotations as in `AVLTree`),
+///    but the structure is balanced on average. The generator is seeded(`with_seed`), so the same inserts build the same list.
+/// 3. Nodes are stored in a vector and link each other by indexes, so there are no `Rc`/`RefCell` at all. Slots of removed nodes are reused.
+///
+/// # Complexity
+/// `insert`, `contains`, `remove` - `O(log n)` expected, `O(n)` in the worst(very unlikely) case.
+pub struct SkipList<T> {
+    nodes: Vec<SkipListNode<T>>,
+    free_slots: Vec<usize>,
+    len: usize,
+    random_state: u64,
+}
+
+impl<T> SkipList<T>
+where
+    T: Ord,
+{
+    /// Creates a list with a fixed seed, so levels are the same on every run.
+    #[must_use]
+    pub fn new() -> Self {
+        SkipList::with_seed(0x2545_f491_4f6c_dd1d)
+    }
+
+    /// Creates a list where levels of nodes are generated from `seed`. `0` is replaced with `1`, as xorshift generator stays `0` forever.
+    #[must_use]
+    pub fn with_seed(seed: u64) -> Self {
+        let head = SkipListNode {
+            value: None,
+            next: vec![None; MAX_LEVEL],
+        };
+
+        SkipList {
+            nodes: vec![head],
+            free_slots: vec![],
+            len: 0,
+            random_state: seed.max(1),
+        }
+    }
+
+    /// Adds a value. Returns `false` if the value is already in the list, the list stays unchanged in this case.
+    pub fn insert(&mut self, value: T) -> bool {
+        let previous = self.find_previous(&value);
+
+        if self.is_next_equal(previous[0], &value) {
+            return false;
+        }
+
+        let level = self.random_level();
+        let next = (0..level)
+            .map(|level| self.nodes[previous[level]].next[level])
+            .collect();
+        let node = SkipListNode {
+            value: Some(value),
+            next,
+        };
+
+        let index = if let Some(index) = self.free_slots.pop() {
+            self.nodes[index] = node;
+            index
+        } else {
+            self.nodes.push(node);
+            self.nodes.len() - 1
+        };
+
+        for (level, &previous) in previous.iter().enumerate().take(level) {
+            self.nodes[previous].next[level] = Some(index);
+        }
+
+        self.len += 1;
+
+        true
+    }
+
+    #[must_use]
+    pub fn contains(&self, value: &T) -> bool {
+        let previous = self.find_previous(value);
+
+        self.is_next_equal(previous[0], value)
+    }
+
+    /// Removes a value. Returns `false` if there is no such value in the list.
+    pub fn remove(&mut self, value: &T) -> bool {
+        let previous = self.find_previous(value);
+
+        if !self.is_next_equal(previous[0], value) {
+            return false;
+        }
+
+        let index = self.nodes[previous[0]].next[0].unwrap();
+        let next = mem::take(&mut self.nodes[index].next);
+
+        // On every level of the removed node, the last lower node pointed to it, so it points to the next one now
+        for (level, next) in next.into_iter().enumerate() {
+            self.nodes[previous[level]].next[level] = next;
+        }
+
+        self.nodes[index].value = None;
+        self.free_slots.push(index);
+        self.len -= 1;
+
+        true
+    }
+
+    /// Iterates over values in ascending order, it's just going through the lowest level.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        let mut next = self.nodes[HEAD].next[0];
+
+        iter::from_fn(move || {
+            let node = &self.nodes[next?];
+
+            next = node.next[0];
+            node.value.as_ref()
+        })
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the last node with a value lower than `value` on every level, i.e. nodes after which `value` is or has to be.
+    fn find_previous(&self, value: &T) -> [usize; MAX_LEVEL] {
+        let mut previous = [HEAD; MAX_LEVEL];
+        let mut current = HEAD;
+
+        for level in (0..MAX_LEVEL).rev() {
+            while let Some(next) = self.nodes[current].next[level] {
+                if self.nodes[next].value.as_ref() >= Some(value) {
+                    break;
+                }
+
+                current = next;
+            }
+
+            previous[level] = current;
+        }
+
+        previous
+    }
+
+    fn is_next_equal(&self, index: usize, value: &T) -> bool {
+        self.nodes[index].next[0].is_some_and(|next| self.nodes[next].value.as_ref() == Some(value))
+    }
+
+    /// Every next level is twice less likely: a number of trailing zeros of a random number is `k` with probability `1 / 2^(k + 1)`.
+    fn random_level(&mut self) -> usize {
+        // xorshift64
+        self.random_state ^= self.random_state << 13;
+        self.random_state ^= self.random_state >> 7;
+        self.random_state ^= self.random_state << 17;
+
+        (self.random_state.trailing_zeros() as usize + 1).min(MAX_LEVEL)
+    }
+}
+
+impl<T> Default for SkipList<T>
+where
+    T: Ord,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SkipList;
+
+    #[test]
+    fn should_keep_shuffled_values_sorted() {
+        // given
+        let mut list = SkipList::new();
+
+        // 37 and 1000 are coprime, so it's a shuffled range 0..1000
+        for index in 0..1000 {
+            assert!(list.insert(index * 37 % 1000));
+        }
+
+        // then
+        assert_eq!(1000, list.len());
+        assert!((0..1000).all(|value| list.contains(&value)));
+        assert!(!list.contains(&1000));
+        assert!(!list.contains(&-1));
+        assert_eq!(
+            (0..1000).collect::<Vec<_>>(),
+            list.iter().copied().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn should_remove_values() {
+        // given
+        let mut list = SkipList::with_seed(42);
+
+        for value in [5, 1, 9, 3, 7] {
+            list.insert(value);
+        }
+
+        // when
+        assert!(list.remove(&5));
+        assert!(list.remove(&1));
+        assert!(!list.remove(&5));
+        assert!(!list.insert(9));
+
+        // then
+        assert_eq!(vec![&3, &7, &9], list.iter().collect::<Vec<_>>());
+        assert!(!list.contains(&5));
+
+        // Slots of removed nodes are reused
+        assert!(list.insert(4));
+        assert!(list.insert(1));
+        assert_eq!(6, list.nodes.len());
+        assert_eq!(vec![&1, &3, &4, &7, &9], list.iter().collect::<Vec<_>>());
+        assert_eq!(5, list.len());
+    }
+}
@@ -0,0 +1,74 @@
+/// # Description
+///
+/// Stack is a "last in, first out" structure: an item which was added the last is taken the first.
+/// It's backed by a vector, as both pushing and popping happen at the end of it, so both are `O(1)`(amortized for pushing).
+/// Unlike `Queue`, where taking from the beginning of a vector would be `O(n)`, a vector is a natural choice here.
+#[derive(Debug)]
+pub struct Stack<T> {
+    items: Vec<T>,
+}
+
+impl<T> Stack<T> {
+    #[must_use]
+    pub fn new() -> Self {
+        Stack { items: vec![] }
+    }
+
+    pub fn push(&mut self, item: T) {
+        self.items.push(item);
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        self.items.pop()
+    }
+
+    /// Returns the item which is going to be taken next without taking it.
+    #[must_use]
+    pub fn peek(&self) -> Option<&T> {
+        self.items.last()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+}
+
+impl<T> Default for Stack<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Stack;
+
+    #[test]
+    fn should_take_last_added_item_first() {
+        // given
+        let mut stack = Stack::new();
+
+        stack.push(1);
+        stack.push(2);
+        stack.push(3);
+
+        // then
+        assert_eq!(Some(&3), stack.peek());
+        assert_eq!(3, stack.len());
+        assert_eq!(Some(3), stack.pop());
+        assert_eq!(Some(2), stack.pop());
+
+        stack.push(4);
+
+        assert_eq!(Some(4), stack.pop());
+        assert_eq!(Some(1), stack.pop());
+        assert_eq!(None, stack.pop());
+        assert!(stack.is_empty());
+    }
+}
@@ -0,0 +1,275 @@
+#![allow(clippy::module_name_repetitions)]
+
+use crate::graph::GraphError;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::mem;
+use std::sync::{Arc, PoisonError, RwLock, RwLockReadGuard};
+
+pub struct SyncEdge<K> {
+    weight: i32,
+    node: Arc<SyncWeightedGraphNode<K>>,
+}
+
+impl<K> SyncEdge<K> {
+    #[must_use]
+    pub fn weight(&self) -> i32 {
+        self.weight
+    }
+
+    #[must_use]
+    pub fn node(&self) -> &Arc<SyncWeightedGraphNode<K>> {
+        &self.node
+    }
+}
+
+pub struct SyncWeightedGraphNode<K> {
+    id: K,
+    nodes: RwLock<Vec<SyncEdge<K>>>,
+}
+
+impl<K> SyncWeightedGraphNode<K>
+where
+    K: Ord + Hash + Clone + Eq,
+{
+    #[must_use]
+    pub fn new(id: K) -> Self {
+        Self {
+            id,
+            nodes: RwLock::new(vec![]),
+        }
+    }
+
+    #[must_use]
+    pub fn id(&self) -> &K {
+        &self.id
+    }
+
+    /// Returns outgoing edges of a node. Any number of threads can read them at the same time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a thread panicked while it was connecting this node, as edges might be left in an unknown state.
+    pub fn nodes(&self) -> RwLockReadGuard<'_, Vec<SyncEdge<K>>> {
+        self.nodes.read().expect("Edges lock is poisoned")
+    }
+}
+
+/// # Description
+/// The same as `WeightedGraph`, but it can be shared between threads(e.g. via `Arc<SyncWeightedGraph>`) to run queries concurrently.
+/// `WeightedGraph` is built on `Rc` and `RefCell`, which are cheaper, but can't cross threads, so it's still the default choice for a single thread.
+///
+/// Realisation details:
+/// 1. Nodes are linked with `Arc` instead of `Rc`, so the counter of references is atomic.
+/// 2. Edges of a node are behind `RwLock` instead of `RefCell`, so many threads can read them at once, and writing waits for all readers.
+/// 3. As in `WeightedGraph`, a cycle of edges is a reference cycle of `Arc`s, so `Drop` calls `clear`, which breaks all edges first.
+pub struct SyncWeightedGraph<K = i32>(HashMap<K, Arc<SyncWeightedGraphNode<K>>>);
+
+impl<K> SyncWeightedGraph<K>
+where
+    K: Ord + Hash + Clone + Eq,
+{
+    #[must_use]
+    pub fn new() -> Self {
+        SyncWeightedGraph(HashMap::new())
+    }
+
+    pub fn insert(&mut self, id: K) {
+        let node = Arc::new(SyncWeightedGraphNode::new(id));
+
+        self.0.insert(node.id.clone(), node);
+    }
+
+    /// Unlike `insert`, which replaces an existing node with the same id(and drops all its edges), this method keeps the existing node.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GraphError::DuplicateNode` if a node with the same id already exists, the graph stays unchanged in this case.
+    pub fn try_insert(&mut self, id: K) -> Result<(), GraphError<K>> {
+        if self.0.contains_key(&id) {
+            return Err(GraphError::DuplicateNode(id));
+        }
+
+        self.insert(id);
+
+        Ok(())
+    }
+
+    ///
+    /// # Panics
+    ///
+    /// If `from_node_id` or `to_node_id` does not exist in a `SyncWeightedGraph`, then this method will panic at either of them.
+    /// Use `try_connect` to handle it without panicking.
+    pub fn connect(&mut self, from_node_id: K, to_node_id: K, edge_weight: i32) {
+        match self.try_connect(from_node_id, to_node_id, edge_weight) {
+            Err(GraphError::FromNodeNotFound(_)) => {
+                panic!("Passed \"from_node_id\" does not exist")
+            }
+            Err(GraphError::ToNodeNotFound(_)) => panic!("Passed \"to_node_id\" does not exist"),
+            _ => {}
+        }
+    }
+
+    ///
+    /// # Errors
+    ///
+    /// Returns `GraphError::FromNodeNotFound` or `GraphError::ToNodeNotFound` with a missing id if either of nodes does not exist.
+    /// `from_node_id` is checked first. The graph stays unchanged in case of an error.
+    ///
+    /// # Panics
+    ///
+    /// Panics if edges lock of `from_node_id` is poisoned.
+    pub fn try_connect(
+        &mut self,
+        from_node_id: K,
+        to_node_id: K,
+        edge_weight: i32,
+    ) -> Result<(), GraphError<K>> {
+        let Some(from_node) = self.get(&from_node_id) else {
+            return Err(GraphError::FromNodeNotFound(from_node_id));
+        };
+        let Some(to_node) = self.get(&to_node_id) else {
+            return Err(GraphError::ToNodeNotFound(to_node_id));
+        };
+
+        from_node
+            .nodes
+            .write()
+            .expect("Edges lock is poisoned")
+            .push(SyncEdge {
+                weight: edge_weight,
+                node: Arc::clone(to_node),
+            });
+
+        Ok(())
+    }
+
+    /// Iterates over all nodes of a graph. Nodes are stored in a `HashMap`, so the order is not specified.
+    pub fn nodes(&self) -> impl Iterator<Item = &Arc<SyncWeightedGraphNode<K>>> {
+        self.0.values()
+    }
+
+    #[must_use]
+    pub fn get(&self, node_id: &K) -> Option<&Arc<SyncWeightedGraphNode<K>>> {
+        self.0.get(node_id)
+    }
+
+    /// Returns outgoing edges of a node as `(neighbour id, weight)` pairs in the order they were connected, or `None` if the node does not exist.
+    /// It's a snapshot, so it doesn't keep the edges lock.
+    #[must_use]
+    pub fn edges_from(&self, node_id: &K) -> Option<Vec<(K, i32)>> {
+        let edges = self
+            .get(node_id)?
+            .nodes()
+            .iter()
+            .map(|edge| (edge.node.id.clone(), edge.weight))
+            .collect();
+
+        Some(edges)
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl<K> Default for SyncWeightedGraph<K>
+where
+    K: Ord + Hash + Clone + Eq,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// `clear` is used by `Drop`, and `Drop` can't have more bounds than the struct itself, so it's a separate impl without bounds
+impl<K> SyncWeightedGraph<K> {
+    /// Removes all nodes and edges from the graph, the same way as `WeightedGraph::clear`: edges are taken out of every reachable node,
+    /// so reference cycles are broken before nodes are dropped, and there is no recursive drop even for long chains.
+    ///
+    /// A poisoned lock doesn't stop us, edges are taken anyway, as they are going to be dropped and nobody reads them after that.
+    ///
+    /// # Complexity
+    /// `O(n + e)`, where `n` is a number of nodes and `e` is a number of edges.
+    pub fn clear(&mut self) {
+        let mut stack: Vec<_> = self.0.drain().map(|(_, node)| node).collect();
+
+        while let Some(node) = stack.pop() {
+            let edges = mem::take(&mut *node.nodes.write().unwrap_or_else(PoisonError::into_inner));
+
+            stack.extend(edges.into_iter().map(|edge| edge.node));
+        }
+    }
+}
+
+impl<K> Drop for SyncWeightedGraph<K> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SyncWeightedGraph;
+    use crate::graph::GraphError;
+    use std::sync::{Arc, Weak};
+
+    #[test]
+    fn should_connect_existing_nodes() {
+        // given
+        let mut graph = SyncWeightedGraph::new();
+
+        graph.insert(1);
+        graph.insert(2);
+
+        // then
+        assert_eq!(Ok(()), graph.try_connect(1, 2, 5));
+        assert_eq!(
+            Err(GraphError::FromNodeNotFound(3)),
+            graph.try_connect(3, 2, 5)
+        );
+        assert_eq!(
+            Err(GraphError::ToNodeNotFound(3)),
+            graph.try_connect(1, 3, 5)
+        );
+        assert_eq!(Err(GraphError::DuplicateNode(1)), graph.try_insert(1));
+        assert_eq!(Some(vec![(2, 5)]), graph.edges_from(&1));
+        assert_eq!(2, graph.len());
+    }
+
+    #[test]
+    fn should_free_cyclic_nodes_on_drop() {
+        // given
+        let mut graph = SyncWeightedGraph::new();
+
+        graph.insert(1);
+        graph.insert(2);
+        graph.insert(3);
+        graph.connect(1, 2, 1);
+        graph.connect(2, 1, 1);
+        graph.connect(2, 3, 1);
+        graph.connect(3, 3, 1);
+
+        let node = Arc::downgrade(graph.get(&1).unwrap());
+        // The old node 3 is not in the graph anymore, but 2 still points to it, and it points to itself
+        let replaced_node = Arc::downgrade(graph.get(&3).unwrap());
+
+        graph.insert(3);
+
+        // The graph and the edge from 2 keep the node
+        assert_eq!(2, Weak::strong_count(&node));
+
+        // when
+        drop(graph);
+
+        // then
+        assert_eq!(0, Weak::strong_count(&node));
+        assert_eq!(0, Weak::strong_count(&replaced_node));
+    }
+}
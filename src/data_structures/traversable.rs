@@ -0,0 +1,15 @@
+use std::rc::Rc;
+
+/// # Description
+/// Anything which has children and can be walked through, e.g. nodes of graphs and trees.
+/// `GraphNode` and `TreeNode` both keep children in a `RefCell`, so traversal algorithms can't be written once for both of them
+/// via `nodes()`, instead they use `children`, which hides the `RefCell` from them.
+///
+/// Children are returned as a new `Vec` of cloned `Rc`s, so no borrow is held while an algorithm visits them.
+///
+/// It's implemented for `BasicGraphNode` and `BasicTreeNode` instead of all `GraphNode`s and `TreeNode`s: two blanket impls
+/// (`impl<N: GraphNode> Traversable for N` and the same for `TreeNode`) would overlap, as a type may implement both traits, and Rust doesn't allow it.
+/// Other node types implement it themselves, it's just a clone of `nodes()`.
+pub trait Traversable {
+    fn children(&self) -> Vec<Rc<Self>>;
+}
@@ -4,10 +4,13 @@
 ///     I've tried to make `GraphNode`'s nodes `RefCell`, but it doesn't seem super straight forward how to do it for `breadth_first_search` algorithm, integration with `Queue` is breaking when I'm trying.
 ///     So for now I'm not going to use `Graph`/`GraphNode` traits here as I want to implement `Tree` first, then try to move `Graph` to mutable nodes and only then use `Graph`/`GraphNode` traits here.
 ///     I'm not doing it all at one as it seems confusing and time consuming, so I'm going to splitting tasks.
+///     Traversal algorithms(BFS/DFS) don't depend on either of these traits already, they work over `Traversable` nodes.
+use crate::Traversable;
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::fmt::Debug;
 use std::hash::Hash;
+use std::ptr;
 use std::rc::{Rc, Weak};
 
 /// The difference between a Graph and a Tree is that a Tree can't have cycles, i.e. nodes of a tree can't point to each other in both ways(children can't point to parents).
@@ -19,6 +22,13 @@ pub trait TreeNode<V, K> {
     fn nodes(&self) -> &RefCell<Vec<Rc<Self>>>;
 }
 
+/// Errors which trees return instead of panicking.
+#[derive(Debug, PartialEq, Eq)]
+pub enum TreeError<K> {
+    /// A node with this id was expected to be a parent, but it doesn't exist.
+    ParentNotFound(K),
+}
+
 pub trait Tree<Node, V, K>
 where
     Node: TreeNode<V, K>,
@@ -48,30 +58,63 @@ impl<V, K> BasicTreeNode<V, K> {
             nodes: RefCell::new(vec![]),
         }
     }
+
+    /// Returns the first child with `value`, or `None` if there is no such child. Only direct children are checked, not the whole subtree.
+    #[must_use]
+    pub fn child_with_value(&self, value: &V) -> Option<Rc<Self>>
+    where
+        V: PartialEq,
+    {
+        self.nodes
+            .borrow()
+            .iter()
+            .find(|child| &child.value == value)
+            .cloned()
+    }
+
+    /// Returns other children of the node's parent in their order. The head doesn't have a parent, so it doesn't have siblings either.
+    #[must_use]
+    pub fn siblings(&self) -> Vec<Rc<Self>> {
+        let Some(parent) = self.parent.as_ref().and_then(Weak::upgrade) else {
+            return vec![];
+        };
+
+        let siblings = parent
+            .nodes
+            .borrow()
+            .iter()
+            .filter(|child| !ptr::eq(child.as_ref(), self))
+            .cloned()
+            .collect();
+
+        siblings
+    }
 }
 
 impl<V, K> TreeNode<V, K> for BasicTreeNode<V, K>
 where
     K: Hash + Eq,
 {
-    #[must_use]
     fn id(&self) -> &K {
         &self.id
     }
-    #[must_use]
     fn value(&self) -> &V {
         &self.value
     }
-    #[must_use]
     fn parent(&self) -> &Option<Weak<Self>> {
         &self.parent
     }
-    #[must_use]
     fn nodes(&self) -> &RefCell<Vec<Rc<Self>>> {
         &self.nodes
     }
 }
 
+impl<V, K> Traversable for BasicTreeNode<V, K> {
+    fn children(&self) -> Vec<Rc<Self>> {
+        self.nodes.borrow().clone()
+    }
+}
+
 pub struct BasicTree<V, K = i32> {
     head: Rc<BasicTreeNode<V, K>>,
     tree: HashMap<K, Rc<BasicTreeNode<V, K>>>,
@@ -79,11 +122,17 @@ pub struct BasicTree<V, K = i32> {
 
 impl<V, K> BasicTree<V, K>
 where
-    K: Eq + Hash + Copy + Debug,
+    K: Eq + Hash + Clone + Debug,
 {
     #[must_use]
     pub fn from_head(head_id: K, head_value: V) -> Self {
-        let mut tree = HashMap::new();
+        BasicTree::with_capacity(head_id, head_value, 1)
+    }
+
+    /// The same as `from_head`, but the tree has space for at least `capacity` nodes(including the head), so there is no rehashing till the tree grows bigger.
+    #[must_use]
+    pub fn with_capacity(head_id: K, head_value: V, capacity: usize) -> Self {
+        let mut tree = HashMap::with_capacity(capacity);
         let head = Rc::new(BasicTreeNode {
             id: head_id,
             parent: None,
@@ -91,7 +140,7 @@ where
             nodes: RefCell::new(vec![]),
         });
 
-        tree.insert(head.id, Rc::clone(&head));
+        tree.insert(head.id.clone(), Rc::clone(&head));
 
         Self { head, tree }
     }
@@ -107,42 +156,222 @@ where
     ///
     /// # Panics
     ///
-    /// Panics if provided `parent_id` does not exist.
+    /// Panics if provided `parent_id` does not exist. Use `try_insert` to handle it without panicking.
     pub fn insert(&mut self, id: K, parent_id: K, value: V) {
-        let parent = self.get(&parent_id);
+        if let Err(TreeError::ParentNotFound(parent_id)) = self.try_insert(id, parent_id, value) {
+            panic!("Can't insert a new leaf, parent with id \"{parent_id:?}\" doesn't exist")
+        }
+    }
 
-        match parent {
-            None => {
-                panic!("Can't insert a new leaf, parent with id \"{parent_id:?}\" doesn't exist")
-            }
-            Some(parent) => {
-                let node = Rc::new(BasicTreeNode::new(id, Rc::downgrade(parent), value));
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `id`: id for new leaf
+    /// * `parent_id`: parent id for new leaf
+    /// * `value`: value for new leaf
+    ///
+    /// returns: `Result<(), TreeError<K>>`
+    ///
+    /// # Errors
+    ///
+    /// Returns `TreeError::ParentNotFound` if provided `parent_id` does not exist, the tree stays unchanged in this case.
+    pub fn try_insert(&mut self, id: K, parent_id: K, value: V) -> Result<(), TreeError<K>> {
+        let Some(parent) = self.get(&parent_id) else {
+            return Err(TreeError::ParentNotFound(parent_id));
+        };
+        let node = Rc::new(BasicTreeNode::new(id.clone(), Rc::downgrade(parent), value));
 
-                parent.nodes.borrow_mut().push(Rc::clone(&node));
-                self.tree.insert(id, node);
-            }
-        }
+        parent.nodes.borrow_mut().push(Rc::clone(&node));
+        self.tree.insert(id, node);
+
+        Ok(())
     }
 }
 
 impl<V, K> Tree<BasicTreeNode<V, K>, V, K> for BasicTree<V, K>
 where
-    K: Eq + Hash + Copy + Debug,
+    K: Eq + Hash + Clone + Debug,
 {
-    #[must_use]
     fn head(&self) -> &Rc<BasicTreeNode<V, K>> {
         &self.head
     }
-    #[must_use]
     fn get(&self, node_id: &K) -> Option<&Rc<BasicTreeNode<V, K>>> {
         self.tree.get(node_id)
     }
-    #[must_use]
     fn len(&self) -> usize {
         self.tree.len()
     }
-    #[must_use]
     fn is_empty(&self) -> bool {
         self.tree.is_empty()
     }
 }
+
+/// Deep-copies a tree: every node gets a new `Rc` and a parent link to the copied parent, so the copy can be changed independently.
+/// Nodes are re-inserted from the head down and children of every node keep their order.
+impl<V, K> Clone for BasicTree<V, K>
+where
+    V: Clone,
+    K: Eq + Hash + Clone + Debug,
+{
+    fn clone(&self) -> Self {
+        let mut tree =
+            BasicTree::with_capacity(self.head.id.clone(), self.head.value.clone(), self.len());
+        let mut pending = vec![Rc::clone(&self.head)];
+
+        while let Some(node) = pending.pop() {
+            for child in node.nodes.borrow().iter() {
+                tree.insert(child.id.clone(), node.id.clone(), child.value.clone());
+                pending.push(Rc::clone(child));
+            }
+        }
+
+        tree
+    }
+}
+
+/// Trees are equal if they have the same nodes(by id) with the same values and the same parents, `Rc`s themselves are not compared.
+/// Ids are unique within a tree, so it's enough to check every node once, and the order of children doesn't matter.
+impl<V, K> PartialEq for BasicTree<V, K>
+where
+    V: PartialEq,
+    K: Eq + Hash + Clone + Debug,
+{
+    fn eq(&self, other: &Self) -> bool {
+        fn parent_id<V, K: Clone>(node: &BasicTreeNode<V, K>) -> Option<K> {
+            node.parent
+                .as_ref()
+                .and_then(Weak::upgrade)
+                .map(|parent| parent.id.clone())
+        }
+
+        self.head.id == other.head.id
+            && self.len() == other.len()
+            && self.tree.iter().all(|(id, node)| {
+                other.get(id).is_some_and(|other_node| {
+                    node.value == other_node.value && parent_id(node) == parent_id(other_node)
+                })
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BasicTree, Tree, TreeError, TreeNode};
+    use std::rc::Rc;
+
+    fn get_tree(leaf_value: &'static str) -> BasicTree<&'static str> {
+        let mut tree = BasicTree::from_head(1, "one");
+
+        tree.insert(2, 1, "two");
+        tree.insert(3, 1, "three");
+        tree.insert(4, 2, leaf_value);
+
+        tree
+    }
+
+    #[test]
+    fn should_be_equal_to_clone() {
+        let tree = get_tree("four");
+
+        assert!(tree == tree.clone());
+        assert!(tree == get_tree("four"));
+    }
+
+    #[test]
+    fn should_differ_by_value_or_structure() {
+        // given
+        let tree = get_tree("four");
+        let mut moved_leaf = BasicTree::from_head(1, "one");
+
+        moved_leaf.insert(2, 1, "two");
+        moved_leaf.insert(3, 1, "three");
+        moved_leaf.insert(4, 3, "four");
+
+        // then
+        assert!(tree != get_tree("FOUR"));
+        assert!(tree != moved_leaf);
+        assert!(tree != BasicTree::from_head(1, "one"));
+    }
+
+    #[test]
+    fn should_clone_independent_tree() {
+        // given
+        let mut tree = BasicTree::from_head(1, "one");
+
+        tree.insert(2, 1, "two");
+        tree.insert(3, 1, "three");
+        tree.insert(4, 2, "four");
+
+        // when
+        let mut cloned = tree.clone();
+
+        cloned.insert(5, 4, "five");
+        cloned.insert(6, 1, "six");
+
+        // then
+        assert_eq!(4, tree.len());
+        assert!(tree.get(&5).is_none());
+        assert!(tree.get(&4).unwrap().nodes().borrow().is_empty());
+        assert_eq!(2, tree.head().nodes().borrow().len());
+
+        assert_eq!(6, cloned.len());
+        assert_eq!(
+            vec![2, 3, 6],
+            cloned
+                .head()
+                .nodes()
+                .borrow()
+                .iter()
+                .map(|node| *node.id())
+                .collect::<Vec<_>>()
+        );
+
+        let cloned_parent = cloned.get(&4).unwrap().parent().as_ref().unwrap().upgrade();
+
+        assert!(Rc::ptr_eq(cloned.get(&2).unwrap(), &cloned_parent.unwrap()));
+        assert!(!Rc::ptr_eq(cloned.get(&2).unwrap(), tree.get(&2).unwrap()));
+    }
+
+    #[test]
+    fn should_insert_under_existing_parent() {
+        let mut tree = BasicTree::from_head(1, "one");
+
+        assert_eq!(Ok(()), tree.try_insert(2, 1, "two"));
+        assert_eq!(2, tree.len());
+        assert_eq!(1, tree.head().nodes().borrow().len());
+    }
+
+    #[test]
+    fn should_behave_the_same_with_capacity() {
+        let mut tree = BasicTree::with_capacity(1, "one", 10);
+
+        tree.insert(2, 1, "two");
+        tree.insert(3, 2, "three");
+
+        assert_eq!(3, tree.len());
+        assert!(tree.tree.capacity() >= 10);
+        assert_eq!(&"three", tree.get(&3).unwrap().value());
+        assert_eq!(1, tree.head().nodes().borrow().len());
+    }
+
+    #[test]
+    fn should_return_error_if_parent_does_not_exist() {
+        let mut tree = BasicTree::from_head(1, "one");
+
+        assert_eq!(
+            Err(TreeError::ParentNotFound(5)),
+            tree.try_insert(2, 5, "two")
+        );
+        assert_eq!(1, tree.len());
+        assert!(tree.get(&2).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "parent with id \"5\" doesn't exist")]
+    fn should_panic_on_insert_if_parent_does_not_exist() {
+        let mut tree = BasicTree::from_head(1, "one");
+
+        tree.insert(2, 5, "two");
+    }
+}
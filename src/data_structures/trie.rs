@@ -0,0 +1,142 @@
+use std::collections::BTreeMap;
+
+#[derive(Debug, Default)]
+struct TrieNode {
+    // `BTreeMap` keeps children sorted, so words are collected in alphabetical order
+    children: BTreeMap<char, TrieNode>,
+    is_word: bool,
+}
+
+/// # Description
+/// `Trie`(prefix tree) keeps a set of words, where every node is a char and every path from the root is a prefix of some words.
+/// Words with a common prefix share nodes of that prefix, so finding all words which start with a prefix(autocomplete) doesn't need to check other words.
+///
+/// A node knows if a word ends at it, e.g. after inserting "car" and "cart", nodes `c -> a -> r -> t` exist, and both `r` and `t` are ends of words.
+///
+/// # Complexity
+/// * `insert`, `contains` - `O(m)`, where `m` is a length of a word.
+/// * `words_with_prefix` - `O(m + k)`, where `m` is a length of the prefix and `k` is a total length of found words.
+#[derive(Debug, Default)]
+pub struct Trie {
+    root: TrieNode,
+    len: usize,
+}
+
+impl Trie {
+    #[must_use]
+    pub fn new() -> Self {
+        Trie::default()
+    }
+
+    /// Adds a word, inserting the same word twice doesn't change the trie.
+    pub fn insert(&mut self, word: &str) {
+        let node = word.chars().fold(&mut self.root, |node, char| {
+            node.children.entry(char).or_default()
+        });
+
+        if !node.is_word {
+            node.is_word = true;
+            self.len += 1;
+        }
+    }
+
+    /// Checks if the exact word was inserted, a prefix of an inserted word doesn't count.
+    #[must_use]
+    pub fn contains(&self, word: &str) -> bool {
+        self.find_node(word).is_some_and(|node| node.is_word)
+    }
+
+    /// Returns all inserted words which start with `prefix`(including `prefix` itself if it's a word) in alphabetical order.
+    /// We go down to a node of the last char of `prefix` and then collect all words under it with DFS.
+    ///
+    /// Returns an empty vector if no word starts with `prefix`. An empty `prefix` returns all words.
+    #[must_use]
+    pub fn words_with_prefix(&self, prefix: &str) -> Vec<String> {
+        fn collect(node: &TrieNode, word: &mut String, words: &mut Vec<String>) {
+            if node.is_word {
+                words.push(word.clone());
+            }
+
+            for (&char, child) in &node.children {
+                word.push(char);
+                collect(child, word, words);
+                word.pop();
+            }
+        }
+
+        let mut words = vec![];
+
+        if let Some(node) = self.find_node(prefix) {
+            collect(node, &mut prefix.to_string(), &mut words);
+        }
+
+        words
+    }
+
+    /// A number of inserted words.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn find_node(&self, prefix: &str) -> Option<&TrieNode> {
+        prefix
+            .chars()
+            .try_fold(&self.root, |node, char| node.children.get(&char))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Trie;
+
+    #[test]
+    fn should_suggest_words_with_prefix() {
+        // given
+        let mut trie = Trie::new();
+
+        for word in ["card", "car", "care", "cat", "dog", "cart", "car"] {
+            trie.insert(word);
+        }
+
+        // then
+        assert_eq!(6, trie.len());
+        assert_eq!(
+            vec!["car", "card", "care", "cart"],
+            trie.words_with_prefix("car")
+        );
+        assert_eq!(
+            vec!["car", "card", "care", "cart", "cat"],
+            trie.words_with_prefix("ca")
+        );
+        assert_eq!(vec!["dog"], trie.words_with_prefix("d"));
+        assert_eq!(6, trie.words_with_prefix("").len());
+    }
+
+    #[test]
+    fn should_not_suggest_anything_for_unknown_prefix() {
+        let mut trie = Trie::new();
+
+        trie.insert("car");
+
+        assert!(trie.words_with_prefix("cat").is_empty());
+        assert!(trie.words_with_prefix("cars").is_empty());
+        assert!(Trie::new().words_with_prefix("a").is_empty());
+    }
+
+    #[test]
+    fn should_contain_only_whole_words() {
+        let mut trie = Trie::new();
+
+        trie.insert("cart");
+
+        assert!(trie.contains("cart"));
+        assert!(!trie.contains("car"));
+        assert!(!trie.contains("carts"));
+    }
+}
@@ -1,8 +1,11 @@
 #![allow(clippy::module_name_repetitions)]
 
+use crate::graph::{BasicGraph, BasicGraphNode, Graph, GraphError};
 use std::cell::{Ref, RefCell};
+use std::collections::hash_map::Values;
 use std::collections::HashMap;
 use std::hash::Hash;
+use std::mem;
 use std::rc::Rc;
 
 pub struct Edge<K> {
@@ -29,7 +32,7 @@ pub struct WeightedGraphNode<K> {
 
 impl<K> WeightedGraphNode<K>
 where
-    K: Ord + Hash + Copy + Eq,
+    K: Ord + Hash + Clone + Eq,
 {
     #[must_use]
     pub fn new(id: K) -> Self {
@@ -40,48 +43,160 @@ where
     }
 
     #[must_use]
-    pub fn id(&self) -> K {
-        self.id
+    pub fn id(&self) -> &K {
+        &self.id
     }
 
     #[must_use]
-    pub fn nodes(&self) -> Ref<Vec<Edge<K>>> {
+    pub fn nodes(&self) -> Ref<'_, Vec<Edge<K>>> {
         Ref::map(self.nodes.borrow(), |x| x)
     }
 }
 
+/// # Description
+/// A directed graph where every edge has a weight. Edges keep strong `Rc`s to nodes they lead to,
+/// so a cycle(e.g. `A -> B -> A`) is a reference cycle, and such nodes would never be freed if we just dropped the `HashMap`.
+/// That's why the graph has `clear`, which breaks all edges first, and `Drop` calls it, so users don't need to call it manually before dropping.
+///
+/// **Please note** that nodes which are still kept outside of the graph(e.g. cloned `Rc`s) are not freed, but they lose all their edges.
 pub struct WeightedGraph<K = i32>(HashMap<K, Rc<WeightedGraphNode<K>>>);
 
 impl<K> WeightedGraph<K>
 where
-    K: Ord + Hash + Copy + Eq,
+    K: Ord + Hash + Clone + Eq,
 {
     #[must_use]
     pub fn new() -> Self {
         WeightedGraph(HashMap::new())
     }
 
+    /// Creates an empty graph with space for at least `capacity` nodes, so there is no rehashing till the graph grows bigger.
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        WeightedGraph(HashMap::with_capacity(capacity))
+    }
+
+    /// Returns a number of nodes the graph can hold without reallocating.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.0.capacity()
+    }
+
     pub fn insert(&mut self, id: K) {
         let node = Rc::new(WeightedGraphNode::new(id));
 
-        self.0.insert(node.id, node);
+        self.0.insert(node.id.clone(), node);
+    }
+    /// Unlike `insert`, which replaces an existing node with the same id(and drops all its edges), this method keeps the existing node.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GraphError::DuplicateNode` if a node with the same id already exists, the graph stays unchanged in this case.
+    pub fn try_insert(&mut self, id: K) -> Result<(), GraphError<K>> {
+        if self.contains(&id) {
+            return Err(GraphError::DuplicateNode(id));
+        }
+
+        self.insert(id);
+
+        Ok(())
     }
     ///
     /// # Panics
     ///
     /// If `from_node_id` or `to_node_id` does not exist in a `WeightedGraph`, then this method will panic at either of them.
+    /// Use `try_connect` to handle it without panicking.
     pub fn connect(&mut self, from_node_id: K, to_node_id: K, edge_weight: i32) {
-        let from_node = self
-            .get(&from_node_id)
-            .expect("Passed \"from_node_id\" does not exist");
-        let to_node = self
-            .get(&to_node_id)
-            .expect("Passed \"to_node_id\" does not exist");
+        match self.try_connect(from_node_id, to_node_id, edge_weight) {
+            Err(GraphError::FromNodeNotFound(_)) => {
+                panic!("Passed \"from_node_id\" does not exist")
+            }
+            Err(GraphError::ToNodeNotFound(_)) => panic!("Passed \"to_node_id\" does not exist"),
+            _ => {}
+        }
+    }
+
+    /// Connects all `edges`(`(from id, to id, weight)`) one by one in the given order, it's the same as calling `connect` for every edge.
+    ///
+    /// # Panics
+    ///
+    /// Panics at the first edge which points from or to a node which does not exist, the same as `connect`.
+    /// Edges before it stay connected.
+    pub fn connect_all(&mut self, edges: &[(K, K, i32)]) {
+        for (from_node_id, to_node_id, edge_weight) in edges {
+            self.connect(from_node_id.clone(), to_node_id.clone(), *edge_weight);
+        }
+    }
+
+    ///
+    /// # Errors
+    ///
+    /// Returns `GraphError::FromNodeNotFound` or `GraphError::ToNodeNotFound` with a missing id if either of nodes does not exist.
+    /// `from_node_id` is checked first. The graph stays unchanged in case of an error.
+    pub fn try_connect(
+        &mut self,
+        from_node_id: K,
+        to_node_id: K,
+        edge_weight: i32,
+    ) -> Result<(), GraphError<K>> {
+        let Some(from_node) = self.get(&from_node_id) else {
+            return Err(GraphError::FromNodeNotFound(from_node_id));
+        };
+        let Some(to_node) = self.get(&to_node_id) else {
+            return Err(GraphError::ToNodeNotFound(to_node_id));
+        };
 
         from_node.nodes.borrow_mut().push(Edge {
             weight: edge_weight,
             node: Rc::clone(to_node),
         });
+
+        Ok(())
+    }
+
+    /// Changes a weight of the edge `from_node_id -> to_node_id` in place, instead of adding one more edge as `connect` does,
+    /// e.g. when costs of a dynamic graph change over time. If there are several(parallel) edges between these nodes, then all of them get `new_weight`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GraphError::FromNodeNotFound` or `GraphError::ToNodeNotFound` with a missing id if either of nodes does not exist,
+    /// or `GraphError::EdgeNotFound` if both nodes exist, but they aren't connected. The graph stays unchanged in case of an error.
+    pub fn update_edge_weight(
+        &mut self,
+        from_node_id: K,
+        to_node_id: K,
+        new_weight: i32,
+    ) -> Result<(), GraphError<K>> {
+        let Some(from_node) = self.get(&from_node_id) else {
+            return Err(GraphError::FromNodeNotFound(from_node_id));
+        };
+
+        if !self.contains(&to_node_id) {
+            return Err(GraphError::ToNodeNotFound(to_node_id));
+        }
+
+        let mut is_found = false;
+
+        for edge in from_node.nodes.borrow_mut().iter_mut() {
+            if edge.node.id == to_node_id {
+                edge.weight = new_weight;
+                is_found = true;
+            }
+        }
+
+        if !is_found {
+            return Err(GraphError::EdgeNotFound {
+                from: from_node_id,
+                to: to_node_id,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Iterates over all nodes of a graph. Nodes are stored in a `HashMap`, so the order is not specified.
+    pub fn nodes(&self) -> impl Iterator<Item = &Rc<WeightedGraphNode<K>>> {
+        self.0.values()
     }
 
     #[must_use]
@@ -89,6 +204,135 @@ where
         self.0.get(node_id)
     }
 
+    #[must_use]
+    pub fn contains(&self, node_id: &K) -> bool {
+        self.0.contains_key(node_id)
+    }
+
+    /// Returns outgoing edges of a node as `(neighbour id, weight)` pairs in the order they were connected, or `None` if the node does not exist.
+    /// Unlike `nodes()` of a node, it's a snapshot, so it doesn't keep the node borrowed.
+    #[must_use]
+    pub fn edges_from(&self, node_id: &K) -> Option<Vec<(K, i32)>> {
+        let edges = self
+            .get(node_id)?
+            .nodes()
+            .iter()
+            .map(|edge| (edge.node.id.clone(), edge.weight))
+            .collect();
+
+        Some(edges)
+    }
+
+    /// Returns all edges of the graph as `(from id, to id, weight)`. Nodes are stored in a `HashMap`, so the order of nodes is not specified,
+    /// but edges of one node are in the order they were connected. As `edges_from`, it's a snapshot, so no node is kept borrowed.
+    #[must_use]
+    pub fn edges(&self) -> Vec<(K, K, i32)> {
+        let mut edges = vec![];
+
+        for node in self.nodes() {
+            edges.extend(
+                node.nodes()
+                    .iter()
+                    .map(|edge| (node.id.clone(), edge.node.id.clone(), edge.weight)),
+            );
+        }
+
+        edges
+    }
+
+    /// Returns the lightest edge as `(from id, to id, weight)`, or `None` if there are no edges.
+    /// If several edges have the same weight, then the one with the lowest `(from id, to id)` is taken, so the result doesn't depend on the `HashMap` order.
+    ///
+    /// # Complexity
+    /// `O(n + e)`, all edges are checked.
+    #[must_use]
+    pub fn min_edge(&self) -> Option<(K, K, i32)> {
+        self.edges()
+            .into_iter()
+            .min_by(|a, b| (a.2, &a.0, &a.1).cmp(&(b.2, &b.0, &b.1)))
+    }
+
+    /// Returns the heaviest edge as `(from id, to id, weight)`, or `None` if there are no edges.
+    /// Ties are resolved the same way as in `min_edge`: the lowest `(from id, to id)` is taken.
+    ///
+    /// # Complexity
+    /// `O(n + e)`, all edges are checked.
+    #[must_use]
+    pub fn max_edge(&self) -> Option<(K, K, i32)> {
+        self.edges()
+            .into_iter()
+            .max_by(|a, b| a.2.cmp(&b.2).then_with(|| (&b.0, &b.1).cmp(&(&a.0, &a.1))))
+    }
+
+    /// Returns a number of outgoing edges of a node or `None` if the node does not exist.
+    #[must_use]
+    pub fn out_degree(&self, node_id: &K) -> Option<usize> {
+        Some(self.get(node_id)?.nodes.borrow().len())
+    }
+
+    /// Returns a number of incoming edges of a node or `None` if the node does not exist.
+    ///
+    /// Nodes don't know who points to them, so we have to check edges of all nodes, which makes it `O(n + e)`.
+    #[must_use]
+    pub fn in_degree(&self, node_id: &K) -> Option<usize> {
+        self.get(node_id)?;
+
+        let degree = self
+            .0
+            .values()
+            .map(|node| {
+                node.nodes
+                    .borrow()
+                    .iter()
+                    .filter(|edge| &edge.node.id == node_id)
+                    .count()
+            })
+            .sum();
+
+        Some(degree)
+    }
+
+    /// Returns a sum of edge weights along `path`, or `None` if any pair of consecutive nodes isn't connected(or a node doesn't exist).
+    /// If there are several edges between two nodes, then the cheapest one is taken. A path of one existing node weights `0`.
+    /// Weights are summed with `saturating_add`, the same way as Dijkstra costs, so a too heavy path weights `i32::MAX` instead of overflowing.
+    #[must_use]
+    pub fn path_weight(&self, path: &[K]) -> Option<i32> {
+        if let Some(first) = path.first() {
+            self.get(first)?;
+        }
+
+        path.windows(2).try_fold(0, |weight: i32, pair| {
+            let edge_weight = self
+                .get(&pair[0])?
+                .nodes()
+                .iter()
+                .filter(|edge| edge.node().id() == &pair[1])
+                .map(Edge::weight)
+                .min()?;
+
+            Some(weight.saturating_add(edge_weight))
+        })
+    }
+
+    /// Returns a `BasicGraph` with the same nodes and edges, but without weights, so BFS based algorithms can be run on the same topology.
+    /// Edges keep their order, and parallel edges stay parallel.
+    #[must_use]
+    pub fn to_unweighted(&self) -> BasicGraph<(), K> {
+        let mut graph = BasicGraph::with_capacity(self.len());
+
+        for node in self.nodes() {
+            graph.insert(Rc::new(BasicGraphNode::new(node.id.clone(), (), None)));
+        }
+
+        for node in self.nodes() {
+            for edge in node.nodes().iter() {
+                graph.connect(node.id.clone(), edge.node.id.clone());
+            }
+        }
+
+        graph
+    }
+
     #[must_use]
     pub fn len(&self) -> usize {
         self.0.len()
@@ -102,9 +346,247 @@ where
 
 impl<K> Default for WeightedGraph<K>
 where
-    K: Ord + Hash + Copy + Eq,
+    K: Ord + Hash + Clone + Eq,
 {
     fn default() -> Self {
         Self::new()
     }
 }
+
+// `clear` is used by `Drop`, and `Drop` can't have more bounds than the struct itself, so it's a separate impl without bounds
+impl<K> WeightedGraph<K> {
+    /// Removes all nodes and edges from the graph.
+    ///
+    /// We go depth first from every node of the graph and take edges out of every node we reach, so reference cycles are broken
+    /// before nodes are dropped. Nodes which were replaced by `insert`, but are still pointed to by edges, are reached via edges as well.
+    /// A node is dropped only after its edges are taken, so there is no recursive drop even for long chains.
+    ///
+    /// # Complexity
+    /// `O(n + e)`, where `n` is a number of nodes and `e` is a number of edges.
+    pub fn clear(&mut self) {
+        let mut stack: Vec<_> = self.0.drain().map(|(_, node)| node).collect();
+
+        while let Some(node) = stack.pop() {
+            let edges = mem::take(&mut *node.nodes.borrow_mut());
+
+            stack.extend(edges.into_iter().map(|edge| edge.node));
+        }
+    }
+}
+
+impl<K> Drop for WeightedGraph<K> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+/// Allows `for node in &graph`, it's the same as `graph.nodes()`, so the order is not specified either.
+impl<'a, K> IntoIterator for &'a WeightedGraph<K> {
+    type Item = &'a Rc<WeightedGraphNode<K>>;
+    type IntoIter = Values<'a, K, Rc<WeightedGraphNode<K>>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.values()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{WeightedGraph, WeightedGraphNode};
+    use crate::graph::GraphError;
+    use std::rc::{Rc, Weak};
+
+    fn get_cyclic_graph() -> (WeightedGraph, Weak<WeightedGraphNode<i32>>) {
+        let mut graph = WeightedGraph::new();
+
+        graph.insert(1);
+        graph.insert(2);
+        graph.insert(3);
+        graph.connect(1, 2, 1);
+        graph.connect(2, 1, 1);
+        graph.connect(2, 3, 1);
+        graph.connect(3, 3, 1);
+
+        let node = Rc::downgrade(graph.get(&1).unwrap());
+
+        (graph, node)
+    }
+
+    #[test]
+    fn should_free_cyclic_nodes_on_clear() {
+        // given
+        let (mut graph, node) = get_cyclic_graph();
+
+        // The graph and the edge from 2 keep the node
+        assert_eq!(2, Weak::strong_count(&node));
+
+        // when
+        graph.clear();
+
+        // then
+        assert_eq!(0, Weak::strong_count(&node));
+        assert!(graph.is_empty());
+
+        graph.insert(1);
+
+        assert!(graph.contains(&1));
+    }
+
+    #[test]
+    fn should_free_cyclic_nodes_on_drop() {
+        // given
+        let (mut graph, node) = get_cyclic_graph();
+        // The old node 3 is not in the graph anymore, but 2 still points to it, and it points to itself
+        let replaced_node = Rc::downgrade(graph.get(&3).unwrap());
+
+        graph.insert(3);
+
+        // when
+        drop(graph);
+
+        // then
+        assert_eq!(0, Weak::strong_count(&node));
+        assert_eq!(0, Weak::strong_count(&replaced_node));
+    }
+
+    #[test]
+    fn should_return_degrees() {
+        // given
+        let mut graph = WeightedGraph::new();
+
+        for id in 1..=4 {
+            graph.insert(id);
+        }
+
+        graph.connect(1, 2, 1);
+        graph.connect(1, 3, 1);
+        graph.connect(1, 4, 1);
+        graph.connect(2, 4, 1);
+        graph.connect(3, 4, 1);
+
+        // then
+        assert_eq!(Some(3), graph.out_degree(&1));
+        assert_eq!(Some(0), graph.in_degree(&1));
+        assert_eq!(Some(0), graph.out_degree(&4));
+        assert_eq!(Some(3), graph.in_degree(&4));
+        assert_eq!(None, graph.out_degree(&5));
+        assert_eq!(None, graph.in_degree(&5));
+        assert_eq!(Some(vec![]), graph.edges_from(&4));
+        assert_eq!(None, graph.edges_from(&5));
+    }
+
+    #[test]
+    fn should_return_path_weight() {
+        // given
+        let mut graph = WeightedGraph::new();
+
+        for id in 1..=4 {
+            graph.insert(id);
+        }
+
+        graph.connect(1, 2, 5);
+        graph.connect(2, 3, 7);
+        graph.connect(2, 3, 2);
+        graph.connect(3, 4, 1);
+
+        // then
+        assert_eq!(Some(8), graph.path_weight(&[1, 2, 3, 4]));
+        assert_eq!(Some(0), graph.path_weight(&[2]));
+        assert_eq!(None, graph.path_weight(&[1, 3, 4]));
+        assert_eq!(None, graph.path_weight(&[4, 3]));
+        assert_eq!(None, graph.path_weight(&[5]));
+
+        // when
+        graph.connect(4, 1, i32::MAX);
+
+        // then
+        assert_eq!(Some(i32::MAX), graph.path_weight(&[3, 4, 1, 2]));
+    }
+
+    #[test]
+    fn should_behave_the_same_with_capacity() {
+        // given
+        let mut graph = WeightedGraph::with_capacity(100);
+
+        // when
+        for id in 1..=100 {
+            graph.insert(id);
+        }
+
+        graph.connect(1, 2, 5);
+
+        // then
+        assert!(graph.capacity() >= 100);
+        assert_eq!(100, graph.len());
+        assert_eq!(Some(vec![(2, 5)]), graph.edges_from(&1));
+    }
+
+    #[test]
+    fn should_not_insert_duplicate() {
+        // given
+        let mut graph = WeightedGraph::new();
+
+        graph.insert(1);
+        graph.insert(2);
+        graph.connect(1, 2, 5);
+
+        // then
+        assert_eq!(Err(GraphError::DuplicateNode(1)), graph.try_insert(1));
+        assert_eq!(Ok(()), graph.try_insert(3));
+        assert_eq!(3, graph.len());
+        assert!(graph.contains(&3));
+        assert!(!graph.contains(&4));
+        assert_eq!(Some(1), graph.out_degree(&1));
+    }
+
+    #[test]
+    fn should_connect_existing_nodes() {
+        let mut graph = WeightedGraph::new();
+
+        graph.insert(1);
+        graph.insert(2);
+
+        assert_eq!(Ok(()), graph.try_connect(1, 2, 5));
+        assert_eq!(Some(1), graph.out_degree(&1));
+    }
+
+    #[test]
+    fn should_return_error_if_from_node_does_not_exist() {
+        let mut graph = WeightedGraph::new();
+
+        graph.insert(2);
+
+        assert_eq!(
+            Err(GraphError::FromNodeNotFound(1)),
+            graph.try_connect(1, 2, 5)
+        );
+        assert_eq!(
+            Err(GraphError::FromNodeNotFound(1)),
+            graph.try_connect(1, 3, 5)
+        );
+    }
+
+    #[test]
+    fn should_return_error_if_to_node_does_not_exist() {
+        let mut graph = WeightedGraph::new();
+
+        graph.insert(1);
+
+        assert_eq!(
+            Err(GraphError::ToNodeNotFound(2)),
+            graph.try_connect(1, 2, 5)
+        );
+        assert_eq!(Some(0), graph.out_degree(&1));
+    }
+
+    #[test]
+    #[should_panic(expected = "\"to_node_id\" does not exist")]
+    fn should_panic_if_bulk_edge_points_to_unknown_node() {
+        let mut graph = WeightedGraph::new();
+
+        graph.insert(1);
+        graph.insert(2);
+
+        graph.connect_all(&[(1, 2, 5), (2, 3, 1)]);
+    }
+}
@@ -1,17 +1,93 @@
+pub use algorithms::bfs_distances;
 pub use algorithms::binary_search;
 pub use algorithms::binary_search_for_tree;
+pub use algorithms::binary_search_instrumented;
 pub use algorithms::breadth_first_search;
+pub use algorithms::breadth_first_search_limited;
+pub use algorithms::breadth_first_search_visit;
+pub use algorithms::connected_components;
+pub use algorithms::counting_cmp;
 pub use algorithms::depth_first_search;
+pub use algorithms::diameter;
+pub use algorithms::dijkstra_all_distances;
+pub use algorithms::dijkstra_multi_source;
 pub use algorithms::dijkstra_search;
+pub use algorithms::dijkstra_search_sync;
+pub use algorithms::dijkstra_tree;
+pub use algorithms::external_merge_sort;
+pub use algorithms::extremum;
+pub use algorithms::find_by_id;
+pub use algorithms::find_cycle;
+pub use algorithms::find_run;
+pub use algorithms::gallop;
+pub use algorithms::gcd;
+pub use algorithms::has_cycle;
+pub use algorithms::has_path;
+pub use algorithms::heapify;
+pub use algorithms::is_connected;
+pub use algorithms::is_heap;
+pub use algorithms::is_stable_sorted;
+pub use algorithms::iterative_deepening_dfs;
+pub use algorithms::k_shortest_paths;
+pub use algorithms::lcm;
+pub use algorithms::longest_increasing_subsequence;
+pub use algorithms::majority_element;
+pub use algorithms::max_flow;
+pub use algorithms::max_subarray;
+pub use algorithms::merge_in_place;
+pub use algorithms::merge_sort;
+pub use algorithms::merge_sort_by;
+pub use algorithms::merge_sort_counted;
+pub use algorithms::merge_sort_with_cutoff;
+pub use algorithms::partition;
+pub use algorithms::partition_point;
+pub use algorithms::pow;
+pub use algorithms::pow_mod;
+pub use algorithms::primes_up_to;
 pub use algorithms::quick_sort;
+pub use algorithms::quick_sort_by;
+pub use algorithms::quick_sort_counted;
+pub use algorithms::quick_sort_range;
+pub use algorithms::rabin_karp;
+pub use algorithms::reachable_sinks;
+pub use algorithms::rle_decode;
+pub use algorithms::rle_encode;
+pub use algorithms::root_to_leaf_paths;
 pub use algorithms::selection_sort;
 pub use algorithms::selection_sort_by_key;
+pub use algorithms::shortest_path;
+pub use algorithms::sift_down;
+pub use algorithms::sift_up;
+pub use algorithms::strongly_connected_components;
+pub use algorithms::ternary_search;
+pub use algorithms::tim_sort;
+pub use algorithms::top_k;
+pub use algorithms::transpose;
+pub use algorithms::two_sum;
+pub use algorithms::zero_one_bfs;
+pub use algorithms::Order;
+pub use algorithms::Search;
+pub use algorithms::MERGE_SORT_CUTOFF;
 
 pub use data_structures::binary_search_tree;
 pub use data_structures::graph;
+pub use data_structures::interval_tree;
+pub use data_structures::segment_tree;
+pub use data_structures::sync_weighted_graph;
 pub use data_structures::tree;
 pub use data_structures::weighted_graph;
+pub use data_structures::BloomFilter;
+pub use data_structures::DaryHeap;
+pub use data_structures::FenwickTree;
+pub use data_structures::IndexedMinHeap;
 pub use data_structures::Queue;
+pub use data_structures::QueueFull;
+pub use data_structures::RingBuffer;
+pub use data_structures::RingBufferFull;
+pub use data_structures::SkipList;
+pub use data_structures::Stack;
+pub use data_structures::Traversable;
+pub use data_structures::Trie;
 
 mod algorithms;
 mod data_structures;